@@ -0,0 +1,410 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+
+/// エンコーディング
+///
+/// 16バイトのUUIDを、ハイフン区切りのUUID文字列（36文字）より短い文字列で表現する。
+/// いずれのエンコーディングも、符号化した文字列を復号すると元の16バイトに戻る。
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use domain_primitives::encoding::Encoding;
+///
+/// let bytes = [0xff; 16];
+/// let encoded = Encoding::Base58.encode(&bytes);
+/// assert_eq!("YcVfxkQb6JRzqk5kF2tNLv", encoded);
+/// assert_eq!(bytes, Encoding::Base58.decode(&encoded).unwrap());
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    /// Bitcoinと同じBase58（16〜22文字）
+    ///
+    /// 紛らわしい`0`、`O`、`I`及び`l`を使用しない。
+    /// 先頭の0のバイトを1文字の`1`で表現し、残りのバイト列を58進数で表現するため、
+    /// 長さはバイト列によって変わる。他のBase58の実装と相互に変換できる。
+    Base58,
+    /// `0-9A-Za-z`を使用したBase62（22文字）
+    Base62,
+    /// Crockford Base32（26文字）
+    ///
+    /// 大文字と小文字を区別せずに復号でき、符号化した文字列はバイト列と同じ順序で並ぶ。
+    Crockford32,
+    /// パディングなしのURLセーフなBase64（22文字）
+    Base64Url,
+}
+
+impl Encoding {
+    /// 16バイトを符号化する。
+    #[cfg(feature = "alloc")]
+    pub fn encode(self, bytes: &[u8; 16]) -> String {
+        let n = u128::from_be_bytes(*bytes);
+        match self {
+            Self::Base58 => encode_base58(bytes),
+            Self::Base62 => encode_radix(n, BASE62_ALPHABET, RADIX_LEN),
+            Self::Crockford32 => encode_radix(n, CROCKFORD_ALPHABET, CROCKFORD_LEN),
+            Self::Base64Url => encode_base64url(n),
+        }
+    }
+
+    /// 符号化された文字列を16バイトに復号する。
+    pub fn decode(self, s: &str) -> Result<[u8; 16], DecodeError> {
+        let n = match self {
+            Self::Base58 => decode_base58(s)?,
+            Self::Base62 => decode_radix(s, RADIX_LEN, |c| find(BASE62_ALPHABET, c))?,
+            Self::Crockford32 => decode_radix(s, CROCKFORD_LEN, decode_crockford_char)?,
+            Self::Base64Url => decode_base64url(s)?,
+        };
+        Ok(n.to_be_bytes())
+    }
+
+    /// 16バイトを符号化した文字列の最短の長さを返す。
+    #[cfg(any(feature = "schemars", feature = "utoipa"))]
+    pub(crate) fn min_len(self) -> usize {
+        match self {
+            Self::Base58 => BASE58_MIN_LEN,
+            Self::Base62 => RADIX_LEN,
+            Self::Crockford32 => CROCKFORD_LEN,
+            Self::Base64Url => BASE64URL_LEN,
+        }
+    }
+
+    /// 16バイトを符号化した文字列の最長の長さを返す。
+    #[cfg(any(feature = "schemars", feature = "utoipa"))]
+    pub(crate) fn max_len(self) -> usize {
+        match self {
+            Self::Base58 | Self::Base62 => RADIX_LEN,
+            Self::Crockford32 => CROCKFORD_LEN,
+            Self::Base64Url => BASE64URL_LEN,
+        }
+    }
+}
+
+/// Base58のアルファベット
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Base62のアルファベット
+const BASE62_ALPHABET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Crockford Base32のアルファベット
+const CROCKFORD_ALPHABET: &[u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// URLセーフなBase64のアルファベット
+const BASE64URL_ALPHABET: &[u8] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Base62で16バイトを符号化した文字列の長さ（Base58の場合は最長の長さ）
+const RADIX_LEN: usize = 22;
+
+/// Base58で16バイトを符号化した文字列の最短の長さ（16バイトがすべて0の場合）
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+const BASE58_MIN_LEN: usize = 16;
+
+/// Crockford Base32で16バイトを符号化した文字列の長さ
+const CROCKFORD_LEN: usize = 26;
+
+/// URLセーフなBase64で16バイトを符号化した文字列の長さ
+const BASE64URL_LEN: usize = 22;
+
+/// 128ビットの整数を、アルファベットの文字数を基数とする固定長の文字列に符号化する。
+///
+/// 文字列の長さに満たない上位の桁は、アルファベットの最初の文字で埋める。
+#[cfg(feature = "alloc")]
+fn encode_radix(mut n: u128, alphabet: &[u8], len: usize) -> String {
+    let radix = alphabet.len() as u128;
+    let mut encoded = vec![alphabet[0]; len];
+    for c in encoded.iter_mut().rev() {
+        *c = alphabet[(n % radix) as usize];
+        n /= radix;
+    }
+    encoded.into_iter().map(char::from).collect()
+}
+
+/// 16バイトをBase58で符号化する。
+///
+/// 先頭の0のバイトはそれぞれ`1`に、残りのバイト列は先頭を`1`で埋めない58進数に符号化する。
+#[cfg(feature = "alloc")]
+fn encode_base58(bytes: &[u8; 16]) -> String {
+    let zeros = bytes.iter().take_while(|&&b| b == 0).count();
+    let mut n = u128::from_be_bytes(*bytes);
+    let mut digits = vec![];
+    while n > 0 {
+        digits.push(BASE58_ALPHABET[(n % 58) as usize]);
+        n /= 58;
+    }
+    let mut encoded = String::with_capacity(zeros + digits.len());
+    encoded.extend(core::iter::repeat_n('1', zeros));
+    encoded.extend(digits.into_iter().rev().map(char::from));
+    encoded
+}
+
+/// Base58の文字列を、128ビットの整数に復号する。
+///
+/// 先頭の`1`の数と、残りの58進数のバイト数の合計が16バイトでなければならない。
+fn decode_base58(s: &str) -> Result<u128, DecodeError> {
+    let zeros = s.chars().take_while(|&c| c == '1').count();
+    let mut n: u128 = 0;
+    for (index, character) in s.chars().enumerate().skip(zeros) {
+        let (value, radix) = find(BASE58_ALPHABET, character)
+            .ok_or(DecodeError::InvalidCharacter { character, index })?;
+        n = n
+            .checked_mul(radix)
+            .and_then(|n| n.checked_add(value))
+            .ok_or(DecodeError::Overflow)?;
+    }
+    let found = zeros + (128 - n.leading_zeros() as usize).div_ceil(8);
+    if found != 16 {
+        return Err(DecodeError::InvalidByteLength { found });
+    }
+    Ok(n)
+}
+
+/// 固定長の文字列を、128ビットの整数に復号する。
+///
+/// `digit`は文字に対応する値を返し、アルファベットの文字数が基数になる。
+fn decode_radix<F>(s: &str, len: usize, digit: F) -> Result<u128, DecodeError>
+where
+    F: Fn(char) -> Option<(u128, u128)>,
+{
+    check_len(s, len)?;
+    let mut n: u128 = 0;
+    for (index, character) in s.chars().enumerate() {
+        let (value, radix) =
+            digit(character).ok_or(DecodeError::InvalidCharacter { character, index })?;
+        n = n
+            .checked_mul(radix)
+            .and_then(|n| n.checked_add(value))
+            .ok_or(DecodeError::Overflow)?;
+    }
+    Ok(n)
+}
+
+/// アルファベットから文字の値を探し、アルファベットの文字数と一緒に返す。
+fn find(alphabet: &[u8], c: char) -> Option<(u128, u128)> {
+    let value = alphabet.iter().position(|&a| char::from(a) == c)?;
+    Some((value as u128, alphabet.len() as u128))
+}
+
+/// Crockford Base32の文字の値を返す。
+///
+/// 大文字と小文字を区別せず、`I`と`L`を`1`、`O`を`0`として扱う。
+fn decode_crockford_char(c: char) -> Option<(u128, u128)> {
+    match c.to_ascii_uppercase() {
+        'O' => Some((0, 32)),
+        'I' | 'L' => Some((1, 32)),
+        c => find(CROCKFORD_ALPHABET, c),
+    }
+}
+
+/// 128ビットの整数を、パディングなしのURLセーフなBase64に符号化する。
+///
+/// 最後の文字は、128ビットの最下位の2ビットを上位に寄せて表現する。
+#[cfg(feature = "alloc")]
+fn encode_base64url(n: u128) -> String {
+    let mut encoded = String::with_capacity(BASE64URL_LEN);
+    for i in 0..BASE64URL_LEN - 1 {
+        let value = (n >> (122 - 6 * i)) & 0x3f;
+        encoded.push(char::from(BASE64URL_ALPHABET[value as usize]));
+    }
+    encoded.push(char::from(BASE64URL_ALPHABET[((n & 0x3) << 4) as usize]));
+    encoded
+}
+
+/// パディングなしのURLセーフなBase64を、128ビットの整数に復号する。
+fn decode_base64url(s: &str) -> Result<u128, DecodeError> {
+    check_len(s, BASE64URL_LEN)?;
+    let mut n: u128 = 0;
+    for (index, character) in s.chars().enumerate() {
+        let invalid = DecodeError::InvalidCharacter { character, index };
+        let (value, _) = find(BASE64URL_ALPHABET, character).ok_or(invalid.clone())?;
+        if index < BASE64URL_LEN - 1 {
+            n = (n << 6) | value;
+        } else if value & 0xf == 0 {
+            // 最後の文字の下位4ビットは、16バイトに含まれないため0でなければならない。
+            n = (n << 2) | (value >> 4);
+        } else {
+            return Err(invalid);
+        }
+    }
+    Ok(n)
+}
+
+/// 文字列の長さを確認する。
+fn check_len(s: &str, expected: usize) -> Result<(), DecodeError> {
+    let found = s.chars().count();
+    if found != expected {
+        return Err(DecodeError::InvalidLength { expected, found });
+    }
+    Ok(())
+}
+
+/// 復号エラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecodeError {
+    /// 文字列の長さが不正
+    InvalidLength {
+        /// 期待する長さ
+        expected: usize,
+        /// 実際の長さ
+        found: usize,
+    },
+    /// 文字列に使用できない文字が含まれている
+    InvalidCharacter {
+        /// 使用できない文字
+        character: char,
+        /// 使用できない文字の位置
+        index: usize,
+    },
+    /// 復号した値が16バイトに収まらない
+    Overflow,
+    /// 復号したバイト列が16バイトでない
+    InvalidByteLength {
+        /// 実際のバイト数
+        found: usize,
+    },
+}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidLength { expected, found } => {
+                write!(f, "invalid length: expected {expected}, found {found}")
+            }
+            Self::InvalidCharacter { character, index } => {
+                write!(f, "invalid character `{character}` at {index}")
+            }
+            Self::Overflow => write!(f, "decoded value does not fit in 16 bytes"),
+            Self::InvalidByteLength { found } => {
+                write!(f, "invalid byte length: expected 16, found {found}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use alloc::format;
+
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    const ENCODINGS: [Encoding; 4] = [
+        Encoding::Base58,
+        Encoding::Base62,
+        Encoding::Crockford32,
+        Encoding::Base64Url,
+    ];
+
+    /// 符号化して復号すると元のバイト列に戻ることを確認
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_round_trip() {
+        for encoding in ENCODINGS {
+            let uuid = uuid::uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8");
+            for bytes in [[0u8; 16], [0xff; 16], uuid.into_bytes()] {
+                let encoded = encoding.encode(&bytes);
+                assert_eq!(bytes, encoding.decode(&encoded).unwrap(), "{encoding:?}");
+            }
+        }
+    }
+
+    /// 各エンコーディングで既知のバイト列を符号化した結果を確認
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_encode() {
+        let bytes = uuid::uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8").into_bytes();
+        assert_eq!("Dq7QdGPZBdz9vwjm3jLQSB", Encoding::Base58.encode(&bytes));
+        assert_eq!("3A30O3qtpTRBe7GtXy50u0", Encoding::Base62.encode(&bytes));
+        assert_eq!(
+            "37WN84845H89QS4HXVD075ZR68",
+            Encoding::Crockford32.encode(&bytes)
+        );
+        assert_eq!("Z-VQRBCxQm-SR7toDl_gyA", Encoding::Base64Url.encode(&bytes));
+        assert_eq!("1111111111111111", Encoding::Base58.encode(&[0; 16]));
+        let mut bytes = [0; 16];
+        bytes[15] = 0x39;
+        assert_eq!("111111111111111z", Encoding::Base58.encode(&bytes));
+        bytes[1] = 0x01;
+        assert_eq!("12d7dWtQMvj9WttA3mMoW", Encoding::Base58.encode(&bytes));
+        assert_eq!(
+            "7ZZZZZZZZZZZZZZZZZZZZZZZZZ",
+            Encoding::Crockford32.encode(&[0xff; 16])
+        );
+    }
+
+    /// Crockford Base32の紛らわしい文字を読み替えて復号することを確認
+    #[test]
+    fn test_crockford_decode_aliases() {
+        assert_eq!(
+            Encoding::Crockford32
+                .decode("00000000000000000000000011")
+                .unwrap(),
+            Encoding::Crockford32
+                .decode("oOOOOOOOOOOOOOOOOOOOOOOOIl")
+                .unwrap()
+        );
+    }
+
+    /// 不正な文字列を復号できないことを確認
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_decode_error() {
+        for encoding in [Encoding::Base62, Encoding::Crockford32, Encoding::Base64Url] {
+            let len = encoding.encode(&[0; 16]).len();
+            assert_eq!(
+                Err(DecodeError::InvalidLength {
+                    expected: len,
+                    found: len - 1
+                }),
+                encoding.decode(&"1".repeat(len - 1)),
+            );
+            assert_eq!(
+                Err(DecodeError::InvalidCharacter {
+                    character: '!',
+                    index: 1
+                }),
+                encoding.decode(&format!("1!{}", "1".repeat(len - 2))),
+            );
+        }
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: '0',
+                index: 1
+            }),
+            Encoding::Base58.decode("10"),
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidByteLength { found: 15 }),
+            Encoding::Base58.decode("111111111111111"),
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidByteLength { found: 17 }),
+            Encoding::Base58.decode("1YcVfxkQb6JRzqk5kF2tNLv"),
+        );
+        assert_eq!(
+            Err(DecodeError::Overflow),
+            Encoding::Base58.decode("zzzzzzzzzzzzzzzzzzzzzzz")
+        );
+        assert_eq!(
+            Err(DecodeError::Overflow),
+            Encoding::Crockford32.decode("80000000000000000000000000")
+        );
+        assert_eq!(
+            Err(DecodeError::Overflow),
+            Encoding::Base62.decode("zzzzzzzzzzzzzzzzzzzzzz")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: 'B',
+                index: 21
+            }),
+            Encoding::Base64Url.decode("AAAAAAAAAAAAAAAAAAAAAB")
+        );
+    }
+}
@@ -1,33 +1,193 @@
-use std::hash::{Hash, Hasher};
-use std::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use alloc::format;
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
 
 use uuid::Uuid;
 
+#[cfg(feature = "alloc")]
+use crate::encoding::{DecodeError, Encoding};
+#[cfg(feature = "rng")]
+use crate::id_generator;
+use crate::id_generator::IdGenerator;
+
+#[cfg(feature = "actix-web")]
+mod actix_web;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "async-graphql")]
+mod async_graphql;
+#[cfg(feature = "axum")]
+mod axum;
+#[cfg(feature = "bincode")]
+mod bincode;
+#[cfg(feature = "borsh")]
+mod borsh;
+/// MongoDBのBSONのバイナリによるエンティティIDのシリアライズ及びデシリアライズ
+///
+/// `bson`フィーチャーを有効にすると、エンティティIDの既定のシリアライズも、BSONではサブタイプ4の
+/// バイナリになる。サブタイプ3のバイナリを使用する場合は、このモジュールの`*_legacy`を指定する。
+#[cfg(feature = "bson")]
+pub mod bson;
+#[cfg(feature = "clap")]
+mod clap;
+#[cfg(feature = "diesel")]
+mod diesel;
+#[cfg(feature = "fake")]
+mod fake;
+#[cfg(any(feature = "actix-web", feature = "axum"))]
+mod id_path;
+mod namespace;
+#[cfg(feature = "postcard")]
+mod postcard;
+#[cfg(feature = "postgres-types")]
+mod postgres_types;
+mod prefix;
+/// `proptest`によるエンティティIDのストラテジー
+#[cfg(feature = "proptest")]
+pub mod proptest;
+/// `prost`によるProtocol BuffersのメッセージとエンティティIDの変換
+#[cfg(feature = "prost")]
+pub mod prost;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "redis")]
+mod redis;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+/// `schemars`によるエンティティIDのJSONスキーマ
+#[cfg(feature = "schemars")]
+pub mod schemars;
+#[cfg(feature = "sea-orm")]
+mod sea_orm;
+/// `serde`によるエンティティIDのシリアライズ及びデシリアライズ
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(any(
+    feature = "sqlx-postgres",
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite"
+))]
+mod sqlx;
+#[cfg(feature = "tracing")]
+mod tracing;
+/// `utoipa`によるエンティティIDのOpenAPIスキーマ
+#[cfg(feature = "utoipa")]
+pub mod utoipa;
+#[cfg(feature = "valuable")]
+mod valuable;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+#[cfg(feature = "async-graphql")]
+pub use self::async_graphql::{EntityScalarName, ScalarId};
+#[cfg(any(feature = "actix-web", feature = "axum"))]
+pub use self::id_path::{IdPath, IdPathRejection};
+pub use self::namespace::EntityNamespace;
+pub use self::prefix::EntityPrefix;
+#[cfg(feature = "rkyv")]
+pub use self::rkyv::ArchivedEntityId;
+
+/// エンティティの型ごとの名前空間を導出するためのルート名前空間
+#[cfg(feature = "alloc")]
+const ROOT_NAMESPACE: Uuid = uuid::uuid!("2cd2ddef-3d84-4db4-b085-696eb3af99fa");
+
+/// スキーマの例に使用するUUID
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+const EXAMPLE_UUID: Uuid = uuid::uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8");
+
 /// エンティティID
 ///
 /// エンティティIDは、エンティティを一意に識別するためのIDを表現する。
 /// エンティティIDは、UUIDを使用して生成される。
 /// エンティティIDは、ジェネリック型`T`を持ち、エンティティの型を表現する。
 /// これにより、異なるエンティティ（構造体）のIDが、同じUUIDを持っていても、型を区別する。
+/// エンティティIDが実装するトレイトは、エンティティの型`T`に境界を要求しない。
+/// また、エンティティIDは`T`に関係なく、常に`Send`及び`Sync`である。
 ///
 /// ```rust
+/// # #[cfg(feature = "rng")] {
 /// use domain_primitives::entity_id::EntityId;
 ///
-/// #[derive(Debug)]
 /// struct Foo;
 /// type FooId = EntityId<Foo>;
 ///
 /// let id1 = FooId::new();
-/// let id2 = FooId::from_uuid(id1.to_uuid());
+/// let id2 = id1;
 /// assert_eq!(id1, id2);
+/// assert_eq!(format!("EntityId<Foo>({id1})"), format!("{id1:?}"));
+/// # }
 /// ```
-#[derive(Debug, Clone)]
-pub struct EntityId<T>(Uuid, PhantomData<T>);
+pub struct EntityId<T>(Uuid, PhantomData<fn() -> T>);
 
 impl<T> EntityId<T> {
     /// コンストラクタ。
+    ///
+    /// `id_generator::with_generator`でIDジェネレーターが上書きされている場合は、それを使用する。
+    /// 乱数生成器を使用するため、`rng`フィーチャーが必要である。
+    /// `wasm`フィーチャーを有効にすると、`wasm32-unknown-unknown`では`getrandom`のJavaScriptの
+    /// バックエンド（`crypto.getRandomValues`）を使用する。
+    #[cfg(feature = "rng")]
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self(Uuid::new_v4(), PhantomData)
+        Self::from_uuid(
+            id_generator::generate_overridden(|g| g.generate()).unwrap_or_else(Uuid::new_v4),
+        )
+    }
+
+    /// 時刻順に並ぶUUIDv7を使用してエンティティIDを生成する。
+    ///
+    /// UUIDv7は生成時刻の順に並ぶため、データベースのB-treeインデックスの断片化を抑えられる。
+    /// `id_generator::with_generator`でIDジェネレーターが上書きされている場合は、その
+    /// `IdGenerator::generate_v7`を使用するため、上書きした場合もUUIDv7を生成し、`created_at`で
+    /// 生成日時を取得できる。
+    /// 現在時刻を使用するため、`std`フィーチャーが必要である。
+    #[cfg(feature = "std")]
+    pub fn new_v7() -> Self {
+        Self::from_uuid(
+            id_generator::generate_overridden(|g| g.generate_v7()).unwrap_or_else(Uuid::now_v7),
+        )
+    }
+
+    /// IDジェネレーターを使用してエンティティIDを生成する。
+    pub fn generate_with(generator: &impl IdGenerator) -> Self {
+        Self::from_uuid(generator.generate())
+    }
+
+    /// 名前空間と名前から決定的なUUIDv5を使用してエンティティIDを生成する。
+    ///
+    /// エンティティの型に`EntityNamespace`を実装している場合は、`from_name`を使用する。
+    pub fn from_name_in(namespace: &Uuid, name: impl AsRef<[u8]>) -> Self {
+        Self::from_uuid(Uuid::new_v5(namespace, name.as_ref()))
+    }
+
+    /// エンティティの型名から導出した名前空間を返す。
+    ///
+    /// `EntityNamespace`を実装できない型のための代替手段で、`from_name_in`と組み合わせて使用する。
+    /// 名前空間は、モジュールパスを除いたエンティティの型名から導出する。
+    /// このため、型の名前を変えると名前空間が変わり、異なるモジュールにある同じ名前の型は、
+    /// 同じ名前空間を共有する。
+    /// 永続化するエンティティIDには、`EntityNamespace`で固定した名前空間を使用すること。
+    ///
+    /// ```rust
+    /// use domain_primitives::entity_id::EntityId;
+    ///
+    /// struct User;
+    /// struct Order;
+    ///
+    /// let user = EntityId::<User>::from_name_in(&EntityId::<User>::type_name_namespace(), "abc");
+    /// let order = EntityId::<Order>::from_name_in(&EntityId::<Order>::type_name_namespace(), "abc");
+    /// assert_ne!(user.to_uuid(), order.to_uuid());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn type_name_namespace() -> Uuid {
+        Uuid::new_v5(&ROOT_NAMESPACE, entity_name::<T>().as_bytes())
     }
 
     /// UUIDからエンティティIDを生成する。
@@ -39,6 +199,65 @@ impl<T> EntityId<T> {
     pub fn to_uuid(&self) -> Uuid {
         self.0
     }
+
+    /// エンコーディングを指定して、エンティティIDを符号化した文字列に変換する。
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "alloc", feature = "rng"))] {
+    /// use domain_primitives::encoding::Encoding;
+    /// use domain_primitives::entity_id::EntityId;
+    ///
+    /// struct Foo;
+    ///
+    /// let id = EntityId::<Foo>::new();
+    /// let encoded = id.encode(Encoding::Base62);
+    /// assert_eq!(22, encoded.len());
+    /// assert_eq!(id, EntityId::decode(&encoded, Encoding::Base62).unwrap());
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn encode(&self, encoding: Encoding) -> String {
+        encoding.encode(self.0.as_bytes())
+    }
+
+    /// エンコーディングを指定して、符号化した文字列からエンティティIDを生成する。
+    #[cfg(feature = "alloc")]
+    pub fn decode(s: &str, encoding: Encoding) -> Result<Self, ParseEntityIdError> {
+        encoding
+            .decode(s)
+            .map(|bytes| Self::from_uuid(Uuid::from_bytes(bytes)))
+            .map_err(|e| {
+                ParseEntityIdError::new::<T>(
+                    s.to_string(),
+                    ParseEntityIdErrorKind::InvalidEncoding(e),
+                )
+            })
+    }
+
+    /// エンティティIDに埋め込まれた生成日時を返す。
+    ///
+    /// UUIDv7（またはv1、v6）以外のUUIDを使用したエンティティIDの場合は`None`を返す。
+    #[cfg(feature = "std")]
+    pub fn created_at(&self) -> Option<std::time::SystemTime> {
+        let (secs, nanos) = self.0.get_timestamp()?.to_unix();
+        Some(std::time::UNIX_EPOCH + std::time::Duration::new(secs, nanos))
+    }
+}
+
+impl<T> Clone for EntityId<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for EntityId<T> {}
+
+impl<T> core::fmt::Debug for EntityId<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "EntityId<{}>(", EntityName::<T>(PhantomData))?;
+        core::fmt::Debug::fmt(&self.0, f)?;
+        f.write_str(")")
+    }
 }
 
 impl<T> PartialEq for EntityId<T> {
@@ -49,31 +268,295 @@ impl<T> PartialEq for EntityId<T> {
 
 impl<T> Eq for EntityId<T> {}
 
+impl<T> PartialOrd for EntityId<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for EntityId<T> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
 impl<T> Hash for EntityId<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.0.hash(state);
     }
 }
 
-impl<T> std::fmt::Display for EntityId<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<T> core::fmt::Display for EntityId<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.0)
     }
 }
 
+/// エンティティの型名から、モジュールパスを取り除いた名前
+///
+/// 例えば、`my_app::domain::User`は`User`に、`alloc::vec::Vec<my_app::User>`は`Vec<User>`になる。
+struct EntityName<T>(PhantomData<fn() -> T>);
+
+impl<T> core::fmt::Display for EntityName<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let is_path = |c: char| c.is_alphanumeric() || c == '_' || c == ':';
+        for segment in core::any::type_name::<T>().split_inclusive(|c: char| !is_path(c)) {
+            let (path, delimiter) = match segment.char_indices().last() {
+                Some((index, c)) if !is_path(c) => segment.split_at(index),
+                _ => (segment, ""),
+            };
+            f.write_str(path.rsplit("::").next().unwrap_or(path))?;
+            f.write_str(delimiter)?;
+        }
+        Ok(())
+    }
+}
+
+/// エンティティの型名から、モジュールパスを取り除いた名前を返す。
+#[cfg(feature = "alloc")]
+pub(crate) fn entity_name<T>() -> String {
+    EntityName::<T>(PhantomData).to_string()
+}
+
+/// エンティティの型名をスネークケースにした名前を返す。
+///
+/// 例えば、`OrderLine`は`order_line`になる。
+#[cfg(any(
+    feature = "actix-web",
+    feature = "axum",
+    feature = "redis",
+    feature = "tracing",
+    feature = "utoipa"
+))]
+pub(crate) fn snake_name<T>() -> String {
+    let mut name = String::new();
+    for c in entity_name::<T>().chars() {
+        if c.is_ascii_uppercase() {
+            if !name.is_empty() && !name.ends_with('_') {
+                name.push('_');
+            }
+            name.push(c.to_ascii_lowercase());
+        } else if c.is_ascii_alphanumeric() {
+            name.push(c);
+        } else if !name.is_empty() && !name.ends_with('_') {
+            name.push('_');
+        }
+    }
+    if name.ends_with('_') {
+        name.pop();
+    }
+    name
+}
+
+/// エンティティの型名から、エンティティIDを表すパスパラメーターの名前を返す。
+///
+/// エンティティの型名をスネークケースにして`_id`を付ける。例えば、`OrderLine`は`order_line_id`になる。
+#[cfg(any(
+    feature = "actix-web",
+    feature = "axum",
+    feature = "tracing",
+    feature = "utoipa"
+))]
+pub(crate) fn param_name<T>() -> String {
+    match snake_name::<T>() {
+        name if name.is_empty() => "id".to_string(),
+        name => format!("{name}_id"),
+    }
+}
+
+/// UUID文字列からエンティティIDを生成する。
+///
+/// ハイフン区切り、シンプル、URN及び波括弧で囲まれた形式のUUID文字列を受け付ける。
+#[cfg(feature = "alloc")]
+impl<T> core::str::FromStr for EntityId<T> {
+    type Err = ParseEntityIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::try_parse(s).map(Self::from_uuid).map_err(|e| {
+            ParseEntityIdError::new::<T>(s.to_string(), ParseEntityIdErrorKind::InvalidUuid(e))
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> TryFrom<&str> for EntityId<T> {
+    type Error = ParseEntityIdError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> TryFrom<String> for EntityId<T> {
+    type Error = ParseEntityIdError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// 16バイトのバイト列からエンティティIDを生成する。
+#[cfg(feature = "alloc")]
+impl<T> TryFrom<&[u8]> for EntityId<T> {
+    type Error = ParseEntityIdError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Uuid::from_slice(value).map(Self::from_uuid).map_err(|e| {
+            ParseEntityIdError::new::<T>(
+                format!("{value:02x?}"),
+                ParseEntityIdErrorKind::InvalidUuid(e),
+            )
+        })
+    }
+}
+
+/// 16バイトのバイト列からエンティティIDを生成する。
+///
+/// 16バイトでない場合はエラーを返す。
+#[cfg(feature = "alloc")]
+impl<T> TryFrom<Vec<u8>> for EntityId<T> {
+    type Error = ParseEntityIdError;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+/// エンティティIDを、UUIDの16バイトのバイト列に変換する。
+#[cfg(feature = "alloc")]
+impl<T> From<EntityId<T>> for Vec<u8> {
+    fn from(value: EntityId<T>) -> Self {
+        value.0.as_bytes().to_vec()
+    }
+}
+
+/// エンティティIDを、ハイフン区切りのUUID文字列に変換する。
+#[cfg(feature = "alloc")]
+impl<T> From<EntityId<T>> for String {
+    fn from(value: EntityId<T>) -> Self {
+        value.to_string()
+    }
+}
+
+/// エンティティID解析エラー
+///
+/// 解析に失敗したエンティティの型名と、解析しようとした入力を保持する。
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEntityIdError {
+    /// エンティティの型名
+    entity: &'static str,
+    /// 解析しようとした入力
+    input: String,
+    /// 解析に失敗した理由
+    kind: ParseEntityIdErrorKind,
+}
+
+#[cfg(feature = "alloc")]
+impl ParseEntityIdError {
+    pub(crate) fn new<T>(input: String, kind: ParseEntityIdErrorKind) -> Self {
+        Self {
+            entity: core::any::type_name::<T>(),
+            input,
+            kind,
+        }
+    }
+
+    /// 解析に失敗したエンティティの型名を返す。
+    pub fn entity(&self) -> &'static str {
+        self.entity
+    }
+
+    /// 解析しようとした入力を返す。
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// 解析に失敗した理由を返す。
+    pub fn kind(&self) -> &ParseEntityIdErrorKind {
+        &self.kind
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for ParseEntityIdError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "invalid entity id for `{}`: `{}`: {}",
+            self.entity, self.input, self.kind
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for ParseEntityIdError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self.kind {
+            ParseEntityIdErrorKind::InvalidUuid(e) => Some(e),
+            ParseEntityIdErrorKind::InvalidEncoding(e) => Some(e),
+            ParseEntityIdErrorKind::PrefixMismatch { .. } => None,
+        }
+    }
+}
+
+/// エンティティIDの解析に失敗した理由
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseEntityIdErrorKind {
+    /// UUIDとして解析できない
+    InvalidUuid(uuid::Error),
+    /// 符号化された文字列を復号できない
+    InvalidEncoding(DecodeError),
+    /// プレフィックスがエンティティの型のプレフィックスと一致しない
+    PrefixMismatch {
+        /// エンティティの型のプレフィックス
+        expected: &'static str,
+        /// 入力のプレフィックス（プレフィックスがない場合は`None`）
+        found: Option<String>,
+    },
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for ParseEntityIdErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidUuid(e) => write!(f, "{e}"),
+            Self::InvalidEncoding(e) => write!(f, "{e}"),
+            Self::PrefixMismatch {
+                expected,
+                found: Some(found),
+            } => write!(f, "expected prefix `{expected}`, found `{found}`"),
+            Self::PrefixMismatch {
+                expected,
+                found: None,
+            } => write!(f, "expected prefix `{expected}`, found none"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "alloc")]
+    use alloc::vec;
+
     use super::*;
+    use crate::id_generator;
+
+    const UUID: Uuid = uuid::uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8");
 
     /// 同じUUIDから作成してエンティティIDが等しいことを確認
     #[test]
     fn test_entity_id_equality() {
-        let id1: EntityId<i32> = EntityId::new();
+        let id1: EntityId<i32> = EntityId::from_uuid(UUID);
         let id2: EntityId<i32> = EntityId::from_uuid(id1.to_uuid());
         assert_eq!(id1, id2);
     }
 
-    /// 異なるUUIDから作成してエンティティIDが等しくないことを確認
+    /// コンストラクタが異なるUUIDからエンティティIDを作成することを確認
+    #[cfg(feature = "rng")]
     #[test]
     fn test_entity_id_inequality() {
         let id1: EntityId<i32> = EntityId::new();
@@ -82,19 +565,189 @@ mod tests {
     }
 
     /// エンティティIDがUUIDと同じハッシュ値を持つことを確認
+    #[cfg(feature = "std")]
     #[test]
     fn test_entity_id_hash() {
-        let mut hasher = std::hash::DefaultHasher::new();
-        let uuid = Uuid::new_v4();
+        let uuid = UUID;
         let id: EntityId<i32> = EntityId::from_uuid(uuid);
-        assert_eq!(uuid.hash(&mut hasher), id.hash(&mut hasher));
+        let mut uuid_hasher = std::hash::DefaultHasher::new();
+        uuid.hash(&mut uuid_hasher);
+        let mut id_hasher = std::hash::DefaultHasher::new();
+        id.hash(&mut id_hasher);
+        assert_eq!(uuid_hasher.finish(), id_hasher.finish());
     }
 
     /// エンティティIDがUUID文字列を表現することを確認
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_entity_id_display() {
-        let uuid = Uuid::new_v4();
+        let uuid = UUID;
         let id: EntityId<u32> = EntityId::from_uuid(uuid);
         assert_eq!(uuid.to_string(), id.to_string());
     }
+
+    /// `Clone`や`Debug`を実装していない型をエンティティの型に使用できることを確認するための構造体
+    struct Foo;
+
+    /// エンティティの型に関係なく、エンティティIDを複製できることを確認
+    #[test]
+    fn test_entity_id_copy() {
+        let id1: EntityId<Foo> = EntityId::from_uuid(UUID);
+        let id2 = id1;
+        #[allow(clippy::clone_on_copy)]
+        let id3 = id1.clone();
+        assert_eq!(id1, id2);
+        assert_eq!(id1, id3);
+    }
+
+    /// エンティティIDのデバッグ表現がエンティティの型名とUUIDを含むことを確認
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_entity_id_debug() {
+        let uuid = UUID;
+        let id: EntityId<Foo> = EntityId::from_uuid(uuid);
+        assert_eq!(format!("EntityId<Foo>({uuid})"), format!("{id:?}"));
+        let id: EntityId<Vec<Foo>> = EntityId::from_uuid(uuid);
+        assert_eq!(format!("EntityId<Vec<Foo>>({uuid})"), format!("{id:?}"));
+    }
+
+    /// UUIDv7を使用したエンティティIDが生成順に並ぶことを確認
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_entity_id_new_v7() {
+        let id1: EntityId<Foo> = EntityId::new_v7();
+        let id2: EntityId<Foo> = EntityId::new_v7();
+        assert_eq!(Some(uuid::Version::SortRand), id1.to_uuid().get_version());
+        assert!(id1 < id2);
+    }
+
+    /// UUIDv7を使用したエンティティIDから生成日時を取得できることを確認
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_entity_id_created_at() {
+        let before = std::time::SystemTime::now() - std::time::Duration::from_millis(1);
+        let id: EntityId<Foo> = EntityId::new_v7();
+        let after = std::time::SystemTime::now();
+        let created_at = id.created_at().unwrap();
+        assert!(before <= created_at && created_at <= after);
+        let id: EntityId<Foo> = EntityId::new();
+        assert_eq!(None, id.created_at());
+    }
+
+    /// IDジェネレーターを使用してエンティティIDを生成できることを確認
+    #[test]
+    fn test_entity_id_generate_with() {
+        let generator = id_generator::SequentialGenerator::new();
+        let id: EntityId<Foo> = EntityId::generate_with(&generator);
+        assert_eq!(Uuid::from_u128(1), id.to_uuid());
+    }
+
+    /// IDジェネレーターを上書きすると、コンストラクタが決定的なエンティティIDを生成することを確認
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_entity_id_new_with_overridden_generator() {
+        let ids = || {
+            id_generator::with_generator(id_generator::SeededGenerator::new(7), || {
+                [EntityId::<Foo>::new(), EntityId::<Foo>::new_v7()]
+            })
+        };
+        assert_eq!(ids(), ids());
+        let [id, id_v7] = ids();
+        assert_eq!(Some(uuid::Version::Random), id.to_uuid().get_version());
+        assert_eq!(Some(uuid::Version::SortRand), id_v7.to_uuid().get_version());
+        assert!(id_v7.created_at().is_some());
+    }
+
+    /// 名前空間と名前から決定的なUUIDv5のエンティティIDが生成されることを確認
+    #[test]
+    fn test_entity_id_from_name_in() {
+        let id1: EntityId<Foo> = EntityId::from_name_in(&Uuid::NAMESPACE_URL, "abc");
+        let id2: EntityId<Foo> = EntityId::from_name_in(&Uuid::NAMESPACE_URL, b"abc");
+        assert_eq!(id1, id2);
+        assert_eq!(Some(uuid::Version::Sha1), id1.to_uuid().get_version());
+        assert_ne!(id1, EntityId::from_name_in(&Uuid::NAMESPACE_URL, "abd"));
+        assert_ne!(id1, EntityId::from_name_in(&Uuid::NAMESPACE_DNS, "abc"));
+    }
+
+    /// エンティティの型名が異なれば、型名から導出した名前空間が異なることを確認
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_entity_id_type_name_namespace() {
+        struct Bar;
+        assert_eq!(
+            EntityId::<Foo>::type_name_namespace(),
+            EntityId::<Foo>::type_name_namespace()
+        );
+        assert_ne!(
+            EntityId::<Foo>::type_name_namespace(),
+            EntityId::<Bar>::type_name_namespace()
+        );
+    }
+
+    /// エンティティIDがUUIDの順序で比較されることを確認
+    #[test]
+    fn test_entity_id_ordering() {
+        let id1: EntityId<Foo> = EntityId::from_uuid(Uuid::from_u128(1));
+        let id2: EntityId<Foo> = EntityId::from_uuid(Uuid::from_u128(2));
+        assert!(id1 < id2);
+        assert_eq!(core::cmp::Ordering::Greater, id2.cmp(&id1));
+    }
+
+    /// エンティティの型に関係なく、エンティティIDが`Send`及び`Sync`であることを確認
+    #[test]
+    fn test_entity_id_send_sync() {
+        fn assert_send_sync<U: Send + Sync>() {}
+        assert_send_sync::<EntityId<*const Foo>>();
+    }
+
+    /// UUID文字列からエンティティIDを解析できることを確認
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_entity_id_from_str() {
+        let uuid = UUID;
+        let id: EntityId<u32> = uuid.to_string().parse().unwrap();
+        assert_eq!(uuid, id.to_uuid());
+        let id = EntityId::<u32>::try_from(uuid.simple().to_string()).unwrap();
+        assert_eq!(uuid, id.to_uuid());
+        let id = EntityId::<u32>::try_from(uuid.urn().to_string().as_str()).unwrap();
+        assert_eq!(uuid, id.to_uuid());
+    }
+
+    /// 16バイトのバイト列からエンティティIDを生成できることを確認
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_entity_id_try_from_bytes() {
+        let uuid = UUID;
+        let id = EntityId::<u32>::try_from(uuid.as_bytes().as_slice()).unwrap();
+        assert_eq!(uuid, id.to_uuid());
+        let err = EntityId::<u32>::try_from([0u8; 15].as_slice()).unwrap_err();
+        assert_eq!("u32", err.entity());
+        let bytes: Vec<u8> = id.into();
+        assert_eq!(uuid.as_bytes().as_slice(), bytes);
+        assert_eq!(id, EntityId::try_from(bytes).unwrap());
+        assert!(EntityId::<u32>::try_from(vec![0u8; 17]).is_err());
+    }
+
+    /// エンティティIDをUUID文字列に変換できることを確認
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_entity_id_into_string() {
+        let id: EntityId<Foo> = EntityId::from_uuid(UUID);
+        let s: String = id.into();
+        assert_eq!(id.to_string(), s);
+        assert_eq!(id, EntityId::try_from(s).unwrap());
+    }
+
+    /// 解析エラーがエンティティの型名と入力を報告することを確認
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_parse_entity_id_error() {
+        let err = "not-a-uuid".parse::<EntityId<u32>>().unwrap_err();
+        assert_eq!("u32", err.entity());
+        assert_eq!("not-a-uuid", err.input());
+        let message = err.to_string();
+        assert!(message.contains("u32"));
+        assert!(message.contains("not-a-uuid"));
+        assert!(matches!(err.kind(), ParseEntityIdErrorKind::InvalidUuid(_)));
+    }
 }
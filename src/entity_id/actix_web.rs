@@ -0,0 +1,76 @@
+use std::future::{Ready, ready};
+
+use actix_web::dev::Payload;
+use actix_web::http::StatusCode;
+use actix_web::{FromRequest, HttpRequest, HttpResponse, ResponseError};
+
+use super::{IdPath, IdPathRejection};
+
+impl<T> FromRequest for IdPath<T> {
+    type Error = IdPathRejection;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(Self::from_params(req.match_info().iter()))
+    }
+}
+
+impl ResponseError for IdPathRejection {
+    fn status_code(&self) -> StatusCode {
+        self.status_as(StatusCode::BAD_REQUEST, StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code())
+            .content_type("application/problem+json")
+            .body(self.problem_json())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::header;
+    use actix_web::{App, test, web};
+
+    use super::*;
+    use crate::entity_id::EntityId;
+
+    struct User;
+
+    async fn get_user(IdPath(id): IdPath<User>) -> String {
+        id.to_string()
+    }
+
+    /// パスパラメーターからエンティティIDを取り出せることを確認
+    #[actix_web::test]
+    async fn test_id_path() {
+        let app =
+            test::init_service(App::new().route("/users/{user_id}", web::get().to(get_user))).await;
+        let id: EntityId<User> = EntityId::new();
+        let req = test::TestRequest::get()
+            .uri(&format!("/users/{id}"))
+            .to_request();
+        let body = test::call_and_read_body(&app, req).await;
+        assert_eq!(id.to_string().as_bytes(), body);
+    }
+
+    /// 不正なエンティティIDを`application/problem+json`形式のレスポンスで拒否することを確認
+    #[actix_web::test]
+    async fn test_id_path_rejection() {
+        let app =
+            test::init_service(App::new().route("/users/{user_id}", web::get().to(get_user))).await;
+        let req = test::TestRequest::get()
+            .uri("/users/not-a-uuid")
+            .to_request();
+        let response = test::call_service(&app, req).await;
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+        assert_eq!(
+            "application/problem+json",
+            response.headers().get(header::CONTENT_TYPE).unwrap()
+        );
+        let problem: serde_json::Value = test::read_body_json(response).await;
+        assert_eq!(400, problem["status"]);
+        assert_eq!("User", problem["entity"]);
+        assert_eq!("user_id", problem["parameter"]);
+    }
+}
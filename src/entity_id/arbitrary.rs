@@ -0,0 +1,42 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+use uuid::Uuid;
+
+use super::EntityId;
+
+/// 非構造化データの16バイトから、エンティティIDを生成する。
+///
+/// データが足りない場合は0で埋めるため、空のデータからはnil UUIDのエンティティIDを生成する。
+impl<'a, T> Arbitrary<'a> for EntityId<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        <[u8; 16]>::arbitrary(u).map(|bytes| Self::from_uuid(Uuid::from_bytes(bytes)))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; 16] as Arbitrary<'a>>::size_hint(depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct User;
+
+    /// 非構造化データからエンティティIDを生成できることを確認
+    #[test]
+    fn test_entity_id_arbitrary() {
+        let uuid = Uuid::new_v4();
+        let mut u = Unstructured::new(uuid.as_bytes());
+        assert_eq!(uuid, EntityId::<User>::arbitrary(&mut u).unwrap().to_uuid());
+        assert!(u.is_empty());
+
+        let mut u = Unstructured::new(&[]);
+        assert!(
+            EntityId::<User>::arbitrary(&mut u)
+                .unwrap()
+                .to_uuid()
+                .is_nil()
+        );
+        assert_eq!((16, Some(16)), EntityId::<User>::size_hint(0));
+    }
+}
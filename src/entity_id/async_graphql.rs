@@ -0,0 +1,316 @@
+use std::borrow::Cow;
+use std::ops::Deref;
+
+use async_graphql::parser::types::Field;
+use async_graphql::registry::{MetaType, MetaTypeId, Registry};
+use async_graphql::{
+    ContextSelectionSet, InputType, InputValueError, InputValueResult, OutputType, Positioned,
+    ScalarType, ServerResult, Value,
+};
+
+use super::{EntityId, entity_name};
+
+/// エンティティIDをGraphQLのスカラーとして扱う。
+///
+/// エンティティIDは、GraphQLの`ID`型として、ハイフン区切りのUUID文字列で表現する。
+/// エンティティの型ごとのスカラーとして扱う場合は、`ScalarId`を使用する。
+impl<T> ScalarType for EntityId<T> {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(s) => s.parse().map_err(InputValueError::custom),
+            _ => Err(InputValueError::custom(format_args!(
+                "expected a string for entity id of `{}`, found {value}",
+                std::any::type_name::<T>()
+            ))),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(s) if s.parse::<Self>().is_ok())
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl<T> InputType for EntityId<T> {
+    type RawValueType = Self;
+
+    fn type_name() -> Cow<'static, str> {
+        <async_graphql::ID as InputType>::type_name()
+    }
+
+    /// GraphQLの`ID`型は組み込みのスカラーとして登録済みのため、`async_graphql::ID`に登録を委ねる。
+    fn create_type_info(registry: &mut Registry) -> String {
+        <async_graphql::ID as InputType>::create_type_info(registry)
+    }
+
+    fn parse(value: Option<Value>) -> InputValueResult<Self> {
+        <Self as ScalarType>::parse(value.unwrap_or_default())
+    }
+
+    fn to_value(&self) -> Value {
+        <Self as ScalarType>::to_value(self)
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+}
+
+impl<T> OutputType for EntityId<T> {
+    fn type_name() -> Cow<'static, str> {
+        <async_graphql::ID as OutputType>::type_name()
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        <async_graphql::ID as OutputType>::create_type_info(registry)
+    }
+
+    async fn resolve(
+        &self,
+        _: &ContextSelectionSet<'_>,
+        _: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        Ok(<Self as ScalarType>::to_value(self))
+    }
+}
+
+/// エンティティのGraphQLのスカラー名
+///
+/// エンティティの型に実装すると、`ScalarId`でエンティティの型ごとのスカラーとして扱える。
+/// スカラー名はスキーマ全体で一意でなければならないため、型名から導出せずに明示する。
+pub trait EntityScalarName {
+    /// スカラー名（`UserId`など）
+    const SCALAR_NAME: &'static str;
+}
+
+/// エンティティの型ごとのGraphQLのスカラーとして扱うエンティティID
+///
+/// `EntityId<T>`は`ID`型として公開されるが、`ScalarId<User>`は`UserId`のように、
+/// `EntityScalarName::SCALAR_NAME`の名前のスカラーとして公開される。
+/// 他のエンティティIDと取り違えないように、スキーマの型で区別したい場合に使用する。
+///
+/// ```rust
+/// use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+/// use domain_primitives::entity_id::{EntityScalarName, ScalarId};
+///
+/// struct User;
+/// impl EntityScalarName for User {
+///     const SCALAR_NAME: &'static str = "UserId";
+/// }
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn user(&self, id: ScalarId<User>) -> ScalarId<User> {
+///         id
+///     }
+/// }
+///
+/// let sdl = Schema::new(Query, EmptyMutation, EmptySubscription).sdl();
+/// assert!(sdl.contains("user(id: UserId!): UserId!"));
+/// ```
+pub struct ScalarId<T>(pub EntityId<T>);
+
+impl<T> ScalarId<T> {
+    /// エンティティIDを返す。
+    pub fn into_inner(self) -> EntityId<T> {
+        self.0
+    }
+}
+
+impl<T: EntityScalarName> ScalarId<T> {
+    /// スカラーを登録して、スカラー名を返す。
+    fn register_scalar(registry: &mut Registry) -> String {
+        registry.create_input_type::<Self, _>(MetaTypeId::Scalar, |_| MetaType::Scalar {
+            name: T::SCALAR_NAME.to_string(),
+            description: Some(format!(
+                "The ID of `{}` as a hyphenated UUID string.",
+                entity_name::<T>()
+            )),
+            is_valid: None,
+            visible: None,
+            inaccessible: false,
+            tags: vec![],
+            specified_by_url: None,
+            directive_invocations: vec![],
+            requires_scopes: vec![],
+        })
+    }
+}
+
+impl<T> Deref for ScalarId<T> {
+    type Target = EntityId<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> Clone for ScalarId<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ScalarId<T> {}
+
+impl<T> std::fmt::Debug for ScalarId<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ScalarId").field(&self.0).finish()
+    }
+}
+
+impl<T> From<EntityId<T>> for ScalarId<T> {
+    fn from(value: EntityId<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> From<ScalarId<T>> for EntityId<T> {
+    fn from(value: ScalarId<T>) -> Self {
+        value.0
+    }
+}
+
+impl<T: EntityScalarName> ScalarType for ScalarId<T> {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        <EntityId<T> as ScalarType>::parse(value)
+            .map(Self)
+            .map_err(InputValueError::propagate)
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        <EntityId<T> as ScalarType>::is_valid(value)
+    }
+
+    fn to_value(&self) -> Value {
+        <EntityId<T> as ScalarType>::to_value(&self.0)
+    }
+}
+
+impl<T: EntityScalarName> InputType for ScalarId<T> {
+    type RawValueType = Self;
+
+    fn type_name() -> Cow<'static, str> {
+        Cow::Borrowed(T::SCALAR_NAME)
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        Self::register_scalar(registry)
+    }
+
+    fn parse(value: Option<Value>) -> InputValueResult<Self> {
+        <Self as ScalarType>::parse(value.unwrap_or_default())
+    }
+
+    fn to_value(&self) -> Value {
+        <Self as ScalarType>::to_value(self)
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+}
+
+impl<T: EntityScalarName> OutputType for ScalarId<T> {
+    fn type_name() -> Cow<'static, str> {
+        Cow::Borrowed(T::SCALAR_NAME)
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        Self::register_scalar(registry)
+    }
+
+    async fn resolve(
+        &self,
+        _: &ContextSelectionSet<'_>,
+        _: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        Ok(<Self as ScalarType>::to_value(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema, value};
+
+    use super::*;
+
+    struct User;
+
+    impl EntityScalarName for User {
+        const SCALAR_NAME: &'static str = "UserId";
+    }
+
+    struct Order;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn user(&self, id: EntityId<User>) -> EntityId<User> {
+            id
+        }
+
+        async fn order(&self, id: EntityId<Order>) -> EntityId<Order> {
+            id
+        }
+
+        async fn typed_user(&self, id: ScalarId<User>) -> ScalarId<User> {
+            id
+        }
+    }
+
+    fn schema() -> Schema<Query, EmptyMutation, EmptySubscription> {
+        Schema::new(Query, EmptyMutation, EmptySubscription)
+    }
+
+    /// エンティティIDを引数と戻り値に使用できることを確認
+    #[tokio::test]
+    async fn test_entity_id_graphql_round_trip() {
+        let id: EntityId<User> = EntityId::new();
+        let response = schema()
+            .execute(format!(
+                r#"{{ user(id: "{0}") typedUser(id: "{0}") }}"#,
+                id.to_uuid().simple()
+            ))
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(
+            value!({ "user": id.to_string(), "typedUser": id.to_string() }),
+            response.data
+        );
+    }
+
+    /// 不正な入力のエラーがエンティティの型名を含むことを確認
+    #[tokio::test]
+    async fn test_entity_id_graphql_invalid_input() {
+        let response = schema().execute(r#"{ user(id: "not-a-uuid") }"#).await;
+        assert_eq!(1, response.errors.len());
+        let message = &response.errors[0].message;
+        assert!(message.contains("User"), "{message}");
+        assert!(message.contains("not-a-uuid"), "{message}");
+
+        let response = schema().execute(r#"{ order(id: 1) }"#).await;
+        assert_eq!(1, response.errors.len());
+        assert!(response.errors[0].message.contains("Order"));
+
+        let response = schema().execute(r#"{ typedUser(id: "not-a-uuid") }"#).await;
+        assert_eq!(1, response.errors.len());
+        assert!(response.errors[0].message.contains("User"));
+    }
+
+    /// エンティティIDが`ID`型として、`ScalarId`がエンティティの型ごとのスカラーとして公開されることを確認
+    #[test]
+    fn test_entity_id_graphql_scalar_name() {
+        let sdl = schema().sdl();
+        assert!(sdl.contains("user(id: ID!): ID!"), "{sdl}");
+        assert!(sdl.contains("order(id: ID!): ID!"), "{sdl}");
+        assert!(sdl.contains("typedUser(id: UserId!): UserId!"), "{sdl}");
+        assert!(sdl.contains("scalar UserId"), "{sdl}");
+    }
+}
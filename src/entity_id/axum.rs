@@ -0,0 +1,96 @@
+use axum::extract::rejection::RawPathParamsRejection;
+use axum::extract::{FromRequestParts, RawPathParams};
+use axum::http::request::Parts;
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+
+use super::{IdPath, IdPathRejection};
+
+impl<T, S> FromRequestParts<S> for IdPath<T>
+where
+    S: Send + Sync,
+{
+    type Rejection = IdPathRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        match RawPathParams::from_request_parts(parts, state).await {
+            Ok(params) => Self::from_params(params.iter()),
+            Err(RawPathParamsRejection::InvalidUtf8InPathParam(e)) => Err(Self::invalid_utf8(e)),
+            Err(_) => Self::from_params([]),
+        }
+    }
+}
+
+impl IntoResponse for IdPathRejection {
+    fn into_response(self) -> Response {
+        let status = self.status_as(StatusCode::BAD_REQUEST, StatusCode::INTERNAL_SERVER_ERROR);
+        (
+            status,
+            [(header::CONTENT_TYPE, "application/problem+json")],
+            self.problem_json(),
+        )
+            .into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::Router;
+    use axum::body::{Body, to_bytes};
+    use axum::http::Request;
+    use axum::routing::get;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::entity_id::EntityId;
+
+    struct User;
+
+    async fn get_user(IdPath(id): IdPath<User>) -> String {
+        id.to_string()
+    }
+
+    async fn send(uri: &str) -> Response {
+        let app = Router::new()
+            .route("/users/{user_id}", get(get_user))
+            .route("/users/{user_id}/posts/{id}", get(get_user));
+        app.oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    /// パスパラメーターからエンティティIDを取り出せることを確認
+    #[tokio::test]
+    async fn test_id_path() {
+        let id: EntityId<User> = EntityId::new();
+        for uri in [format!("/users/{id}"), format!("/users/{id}/posts/1")] {
+            let response = send(&uri).await;
+            assert_eq!(StatusCode::OK, response.status());
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            assert_eq!(id.to_string().as_bytes(), body);
+        }
+    }
+
+    /// 不正なエンティティIDを`application/problem+json`形式のレスポンスで拒否することを確認
+    #[tokio::test]
+    async fn test_id_path_rejection() {
+        let response = send("/users/not-a-uuid").await;
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+        assert_eq!(
+            "application/problem+json",
+            response.headers()[header::CONTENT_TYPE]
+        );
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let problem: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(400, problem["status"]);
+        assert_eq!("User", problem["entity"]);
+        assert_eq!("user_id", problem["parameter"]);
+
+        let response = send("/users/%FF").await;
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let problem: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(400, problem["status"]);
+        assert_eq!("user_id", problem["parameter"]);
+    }
+}
@@ -0,0 +1,72 @@
+use bincode::de::{BorrowDecoder, Decoder};
+use bincode::enc::Encoder;
+use bincode::error::{DecodeError, EncodeError};
+use bincode::{BorrowDecode, Decode, Encode};
+use uuid::Uuid;
+
+use super::EntityId;
+
+/// エンティティIDを16バイトのバイト列として符号化する。
+///
+/// バイト列は固定長のため、長さは符号化しない。
+impl<T> Encode for EntityId<T> {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.0.as_bytes().encode(encoder)
+    }
+}
+
+impl<T, Context> Decode<Context> for EntityId<T> {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        <[u8; 16]>::decode(decoder).map(|bytes| Self::from_uuid(Uuid::from_bytes(bytes)))
+    }
+}
+
+impl<'de, T, Context> BorrowDecode<'de, Context> for EntityId<T> {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        <Self as Decode<Context>>::decode(decoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct User;
+
+    #[derive(Debug, PartialEq, Encode, Decode)]
+    struct Event {
+        user_id: EntityId<User>,
+        seq: u32,
+    }
+
+    /// エンティティIDを16バイトで符号化して、元に戻せることを確認
+    #[test]
+    fn test_entity_id_bincode_round_trip() {
+        let config = bincode::config::standard();
+        let id: EntityId<User> = EntityId::new();
+        let bytes = bincode::encode_to_vec(id, config).unwrap();
+        assert_eq!(id.to_uuid().as_bytes().as_slice(), bytes);
+
+        let event = Event {
+            user_id: id,
+            seq: 1,
+        };
+        let bytes = bincode::encode_to_vec(&event, config).unwrap();
+        assert_eq!(17, bytes.len());
+        let (decoded, _): (Event, _) = bincode::decode_from_slice(&bytes, config).unwrap();
+        assert_eq!(event, decoded);
+        let (decoded, _): (EntityId<User>, _) =
+            bincode::borrow_decode_from_slice(&bytes[..16], config).unwrap();
+        assert_eq!(id, decoded);
+    }
+
+    /// バイト列が16バイトに満たない場合に復号できないことを確認
+    #[test]
+    fn test_entity_id_bincode_unexpected_end() {
+        let config = bincode::config::standard();
+        let result: Result<(EntityId<User>, _), _> = bincode::decode_from_slice(&[0; 15], config);
+        assert!(matches!(result, Err(DecodeError::UnexpectedEnd { .. })));
+    }
+}
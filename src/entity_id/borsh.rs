@@ -0,0 +1,53 @@
+use borsh::io::{Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+use uuid::Uuid;
+
+use super::EntityId;
+
+/// エンティティIDを16バイトのバイト列としてシリアライズする。
+impl<T> BorshSerialize for EntityId<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(self.0.as_bytes())
+    }
+}
+
+impl<T> BorshDeserialize for EntityId<T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        <[u8; 16]>::deserialize_reader(reader).map(|bytes| Self::from_uuid(Uuid::from_bytes(bytes)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct User;
+
+    #[derive(Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+    struct Event {
+        user_id: EntityId<User>,
+        seq: u32,
+    }
+
+    /// エンティティIDを16バイトでシリアライズして、元に戻せることを確認
+    #[test]
+    fn test_entity_id_borsh_round_trip() {
+        let id: EntityId<User> = EntityId::new();
+        let bytes = borsh::to_vec(&id).unwrap();
+        assert_eq!(id.to_uuid().as_bytes().as_slice(), bytes);
+
+        let event = Event {
+            user_id: id,
+            seq: 1,
+        };
+        let bytes = borsh::to_vec(&event).unwrap();
+        assert_eq!(20, bytes.len());
+        assert_eq!(event, borsh::from_slice(&bytes).unwrap());
+    }
+
+    /// バイト列が16バイトに満たない場合にデシリアライズできないことを確認
+    #[test]
+    fn test_entity_id_borsh_unexpected_end() {
+        assert!(borsh::from_slice::<EntityId<User>>(&[0; 15]).is_err());
+    }
+}
@@ -0,0 +1,275 @@
+use bson::spec::BinarySubtype;
+use bson::{Binary, Bson, UuidRepresentation};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::EntityId;
+
+impl<T> EntityId<T> {
+    /// UUIDの表現を指定して、エンティティIDをBSONのバイナリに変換する。
+    ///
+    /// `UuidRepresentation::Standard`の場合はサブタイプ4、それ以外の場合はサブタイプ3のバイナリに変換する。
+    pub fn to_bson_binary(&self, representation: UuidRepresentation) -> Binary {
+        Binary::from_uuid_with_representation(self.0.into(), representation)
+    }
+
+    /// UUIDの表現を指定して、BSONのバイナリからエンティティIDを生成する。
+    ///
+    /// バイナリのサブタイプがUUIDの表現と一致しない場合はエラーを返す。
+    pub fn from_bson_binary(
+        binary: &Binary,
+        representation: UuidRepresentation,
+    ) -> Result<Self, bson::uuid::Error> {
+        binary
+            .to_uuid_with_representation(representation)
+            .map(|uuid| Self::from_uuid(uuid.into()))
+    }
+}
+
+/// エンティティIDをサブタイプ4のBSONのバイナリに変換する。
+///
+/// `doc! { "_id": id }`のように、クエリのフィルターにエンティティIDを使用できる。
+impl<T> From<EntityId<T>> for Bson {
+    fn from(value: EntityId<T>) -> Self {
+        Bson::Binary(value.to_bson_binary(UuidRepresentation::Standard))
+    }
+}
+
+/// エンティティIDをサブタイプ4のBSONのバイナリでシリアライズする。
+///
+/// `#[serde(with = "domain_primitives::entity_id::bson::binary")]`のように使用する。
+/// BSON以外の形式では、`bson::Uuid`と同様にシリアライズする。
+/// 既定のシリアライズと同じバイナリになるが、フィールドの表現を明示する場合に使用する。
+pub mod binary {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::entity_id::EntityId;
+
+    /// エンティティIDをシリアライズする。
+    pub fn serialize<T, S>(id: &EntityId<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        bson::Uuid::from(id.0).serialize(serializer)
+    }
+
+    /// エンティティIDをデシリアライズする。
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<EntityId<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        bson::Uuid::deserialize(deserializer).map(|uuid| EntityId::from_uuid(uuid.into()))
+    }
+}
+
+/// UUIDの表現を指定して、エンティティIDをBSONのバイナリでシリアライズする。
+fn serialize_legacy<T, S>(
+    id: &EntityId<T>,
+    representation: UuidRepresentation,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    id.to_bson_binary(representation).serialize(serializer)
+}
+
+/// UUIDの表現を指定して、BSONのバイナリからエンティティIDをデシリアライズする。
+///
+/// サブタイプ3のバイナリは指定したUUIDの表現で、サブタイプ4のバイナリは標準の表現で読み込む。
+fn deserialize_legacy<'de, T, D>(
+    representation: UuidRepresentation,
+    deserializer: D,
+) -> Result<EntityId<T>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let binary = match Bson::deserialize(deserializer)? {
+        Bson::Binary(binary) => binary,
+        other => {
+            return Err(serde::de::Error::custom(format!(
+                "expected a BSON binary for entity id, found `{other}`"
+            )));
+        }
+    };
+    let representation = match binary.subtype {
+        BinarySubtype::Uuid => UuidRepresentation::Standard,
+        _ => representation,
+    };
+    EntityId::from_bson_binary(&binary, representation).map_err(serde::de::Error::custom)
+}
+
+macro_rules! legacy_module {
+    ($(#[$attr:meta])* $name:ident, $representation:expr) => {
+        $(#[$attr])*
+        pub mod $name {
+            use bson::UuidRepresentation;
+            use serde::{Deserializer, Serializer};
+
+            use super::{deserialize_legacy, serialize_legacy};
+            use crate::entity_id::EntityId;
+
+            /// エンティティIDをシリアライズする。
+            pub fn serialize<T, S>(id: &EntityId<T>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serialize_legacy(id, $representation, serializer)
+            }
+
+            /// エンティティIDをデシリアライズする。
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<EntityId<T>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserialize_legacy($representation, deserializer)
+            }
+        }
+    };
+}
+
+legacy_module!(
+    /// エンティティIDを、Javaのドライバーが使用していたサブタイプ3のBSONのバイナリでシリアライズする。
+    ///
+    /// `#[serde(with = "domain_primitives::entity_id::bson::java_legacy")]`のように使用する。
+    /// 古いコレクションを読み込むため、サブタイプ4のバイナリもデシリアライズできる。
+    java_legacy,
+    UuidRepresentation::JavaLegacy
+);
+
+legacy_module!(
+    /// エンティティIDを、C#のドライバーが使用していたサブタイプ3のBSONのバイナリでシリアライズする。
+    ///
+    /// `#[serde(with = "domain_primitives::entity_id::bson::csharp_legacy")]`のように使用する。
+    /// 古いコレクションを読み込むため、サブタイプ4のバイナリもデシリアライズできる。
+    csharp_legacy,
+    UuidRepresentation::CSharpLegacy
+);
+
+legacy_module!(
+    /// エンティティIDを、Pythonのドライバーが使用していたサブタイプ3のBSONのバイナリでシリアライズする。
+    ///
+    /// `#[serde(with = "domain_primitives::entity_id::bson::python_legacy")]`のように使用する。
+    /// 古いコレクションを読み込むため、サブタイプ4のバイナリもデシリアライズできる。
+    python_legacy,
+    UuidRepresentation::PythonLegacy
+);
+
+#[cfg(test)]
+mod tests {
+    use bson::doc;
+    use serde::{Deserialize, Serialize};
+    use uuid::Uuid;
+
+    use super::*;
+
+    struct User;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct UserDocument {
+        #[serde(with = "binary")]
+        id: EntityId<User>,
+        #[serde(with = "java_legacy")]
+        java_id: EntityId<User>,
+        #[serde(with = "csharp_legacy")]
+        csharp_id: EntityId<User>,
+    }
+
+    const UUID: Uuid = uuid::uuid!("00112233-4455-6677-8899-aabbccddeeff");
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct DefaultDocument {
+        id: EntityId<User>,
+        ids: Vec<EntityId<User>>,
+    }
+
+    /// 既定のシリアライズで、エンティティIDをサブタイプ4のBSONのバイナリでシリアライズすることを確認
+    #[test]
+    fn test_entity_id_bson_default_serialize() {
+        let id: EntityId<User> = EntityId::from_uuid(UUID);
+        let document = DefaultDocument { id, ids: vec![id] };
+        let serialized = bson::to_document(&document).unwrap();
+        assert_eq!(doc! { "id": id, "ids": [id] }, serialized);
+        assert_eq!(document, bson::from_document(serialized).unwrap());
+        assert_eq!(Bson::from(id), bson::to_bson(&id).unwrap());
+        let parsed: EntityId<User> = bson::from_bson(Bson::String(UUID.to_string())).unwrap();
+        assert_eq!(id, parsed);
+    }
+
+    /// 既定のシリアライズで、BSONのバイト列に書き込んだエンティティIDを読み込めることを確認
+    ///
+    /// MongoDBのドライバーは、型付きのコレクションの読み書きに`bson::to_vec`及び`bson::from_slice`
+    /// と同じ経路を使用する。
+    #[test]
+    fn test_entity_id_bson_default_raw_round_trip() {
+        let id: EntityId<User> = EntityId::from_uuid(UUID);
+        let document = DefaultDocument {
+            id,
+            ids: vec![id, EntityId::from_uuid(Uuid::nil())],
+        };
+        let bytes = bson::to_vec(&document).unwrap();
+        let raw = bson::RawDocument::from_bytes(&bytes).unwrap();
+        assert_eq!(
+            Some(bson::RawBsonRef::Binary(bson::RawBinaryRef {
+                subtype: BinarySubtype::Uuid,
+                bytes: UUID.as_bytes(),
+            })),
+            raw.get("id").unwrap()
+        );
+        assert_eq!(document, bson::from_slice(&bytes).unwrap());
+    }
+
+    /// エンティティIDをサブタイプ4及びサブタイプ3のBSONのバイナリでシリアライズすることを確認
+    #[test]
+    fn test_entity_id_bson_serialize() {
+        let id: EntityId<User> = EntityId::from_uuid(UUID);
+        let document = UserDocument {
+            id,
+            java_id: id,
+            csharp_id: id,
+        };
+        let serialized = bson::to_document(&document).unwrap();
+        assert_eq!(
+            doc! {
+                "id": Binary { subtype: BinarySubtype::Uuid, bytes: UUID.as_bytes().to_vec() },
+                "java_id": Binary {
+                    subtype: BinarySubtype::UuidOld,
+                    bytes: hex("7766554433221100ffeeddccbbaa9988"),
+                },
+                "csharp_id": Binary {
+                    subtype: BinarySubtype::UuidOld,
+                    bytes: hex("33221100554477668899aabbccddeeff"),
+                },
+            },
+            serialized
+        );
+        assert_eq!(Bson::from(id), serialized.get("id").unwrap().clone());
+        assert_eq!(document, bson::from_document(serialized).unwrap());
+    }
+
+    /// サブタイプ3のUUIDの表現を指定しても、サブタイプ4のバイナリを読み込めることを確認
+    #[test]
+    fn test_entity_id_bson_legacy_reads_standard() {
+        let id: EntityId<User> = EntityId::from_uuid(UUID);
+        let document = doc! { "id": id, "java_id": id, "csharp_id": id };
+        let deserialized: UserDocument = bson::from_document(document).unwrap();
+        assert_eq!(id, deserialized.java_id);
+        assert_eq!(id, deserialized.csharp_id);
+    }
+
+    /// UUIDの表現とバイナリのサブタイプが一致しない場合に読み込めないことを確認
+    #[test]
+    fn test_entity_id_bson_representation_mismatch() {
+        let id: EntityId<User> = EntityId::from_uuid(UUID);
+        let legacy = id.to_bson_binary(UuidRepresentation::JavaLegacy);
+        assert!(EntityId::<User>::from_bson_binary(&legacy, UuidRepresentation::Standard).is_err());
+        let document = doc! { "id": legacy, "java_id": id, "csharp_id": id };
+        assert!(bson::from_document::<UserDocument>(document).is_err());
+    }
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+}
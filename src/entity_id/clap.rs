@@ -0,0 +1,65 @@
+use clap::builder::{StringValueParser, TryMapValueParser, TypedValueParser, ValueParserFactory};
+
+use super::{EntityId, ParseEntityIdError};
+
+/// コマンドライン引数からエンティティIDを解析する。
+///
+/// `FromStr`と同じUUID文字列を受け付け、解析できない場合は、引数の名前、入力値及び
+/// 解析エラーを含むエラーメッセージを表示する。
+impl<T: 'static> ValueParserFactory for EntityId<T> {
+    type Parser =
+        TryMapValueParser<StringValueParser, fn(String) -> Result<Self, ParseEntityIdError>>;
+
+    fn value_parser() -> Self::Parser {
+        StringValueParser::new().try_map(|s| s.parse())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use clap::error::ErrorKind;
+
+    use super::*;
+
+    struct User;
+
+    #[derive(Parser)]
+    struct Cli {
+        #[arg(long)]
+        user: EntityId<User>,
+        #[arg(long)]
+        friends: Vec<EntityId<User>>,
+    }
+
+    /// コマンドライン引数からエンティティIDを解析できることを確認
+    #[test]
+    fn test_entity_id_value_parser() {
+        let id: EntityId<User> = EntityId::new();
+        let friend: EntityId<User> = EntityId::new();
+        let cli = Cli::try_parse_from([
+            "admin",
+            "--user",
+            &id.to_string(),
+            "--friends",
+            &friend.to_string(),
+        ])
+        .unwrap();
+        assert_eq!(id, cli.user);
+        assert_eq!(vec![friend], cli.friends);
+    }
+
+    /// 不正なエンティティIDを解析できず、エラーメッセージに引数と入力値が含まれることを確認
+    #[test]
+    fn test_entity_id_value_parser_error() {
+        let err = match Cli::try_parse_from(["admin", "--user", "not-a-uuid"]) {
+            Ok(_) => panic!("expected an error"),
+            Err(err) => err,
+        };
+        assert_eq!(ErrorKind::ValueValidation, err.kind());
+        let message = err.to_string();
+        assert!(message.contains("--user"), "{message}");
+        assert!(message.contains("not-a-uuid"), "{message}");
+        assert!(message.contains("invalid entity id"), "{message}");
+    }
+}
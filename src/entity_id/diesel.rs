@@ -0,0 +1,235 @@
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql, FromSqlRow};
+use diesel::expression::AsExpression;
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::{Binary, Text};
+use uuid::Uuid;
+
+use super::EntityId;
+
+/// エンティティIDに`AsExpression`及び`FromSqlRow`（`Queryable`）を実装するための構造体
+///
+/// エンティティIDは、`Binary`型及び`Text`型の列として使用でき、PostgreSQLでは`Uuid`型の列としても使用できる。
+/// `Text`型の列への書き込みは、`diesel-mysql`、`diesel-postgres`及び`diesel-sqlite`フィーチャーで
+/// バックエンドごとに有効になる。
+#[derive(AsExpression, FromSqlRow)]
+#[diesel(foreign_derive)]
+#[diesel(sql_type = Binary)]
+#[diesel(sql_type = Text)]
+#[cfg_attr(feature = "diesel-postgres", diesel(sql_type = diesel::sql_types::Uuid))]
+#[allow(dead_code)]
+struct EntityIdProxy<T>(EntityId<T>);
+
+/// エンティティIDを16バイトのバイト列として書き込む。
+impl<T, DB> ToSql<Binary, DB> for EntityId<T>
+where
+    DB: Backend,
+    [u8]: ToSql<Binary, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        <[u8] as ToSql<Binary, DB>>::to_sql(self.0.as_bytes(), out)
+    }
+}
+
+/// 16バイトのバイト列からエンティティIDを読み込む。
+impl<T, DB> FromSql<Binary, DB> for EntityId<T>
+where
+    DB: Backend,
+    Vec<u8>: FromSql<Binary, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let bytes = <Vec<u8> as FromSql<Binary, DB>>::from_sql(bytes)?;
+        Ok(Self::from_uuid(Uuid::from_slice(&bytes)?))
+    }
+}
+
+/// UUID文字列からエンティティIDを読み込む。
+impl<T, DB> FromSql<Text, DB> for EntityId<T>
+where
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let s = <String as FromSql<Text, DB>>::from_sql(bytes)?;
+        Ok(Self::from_uuid(Uuid::try_parse(&s)?))
+    }
+}
+
+/// MySQLの`Text`型への書き込み
+#[cfg(feature = "diesel-mysql")]
+mod mysql {
+    use std::io::Write;
+
+    use diesel::mysql::Mysql;
+    use diesel::serialize::{self, IsNull, Output, ToSql};
+    use diesel::sql_types::Text;
+    use uuid::Uuid;
+
+    use crate::entity_id::EntityId;
+
+    /// エンティティIDをハイフン区切りのUUID文字列として書き込む。
+    impl<T> ToSql<Text, Mysql> for EntityId<T> {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Mysql>) -> serialize::Result {
+            let mut buffer = Uuid::encode_buffer();
+            out.write_all(self.0.hyphenated().encode_lower(&mut buffer).as_bytes())?;
+            Ok(IsNull::No)
+        }
+    }
+}
+
+/// PostgreSQLの`uuid`型との変換及び`Text`型への書き込み
+#[cfg(feature = "diesel-postgres")]
+mod postgres {
+    use std::io::Write;
+
+    use diesel::deserialize::{self, FromSql};
+    use diesel::pg::{Pg, PgValue};
+    use diesel::serialize::{self, IsNull, Output, ToSql};
+    use diesel::sql_types;
+    use uuid::Uuid;
+
+    use crate::entity_id::EntityId;
+
+    /// エンティティIDをハイフン区切りのUUID文字列として書き込む。
+    impl<T> ToSql<sql_types::Text, Pg> for EntityId<T> {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+            let mut buffer = Uuid::encode_buffer();
+            out.write_all(self.0.hyphenated().encode_lower(&mut buffer).as_bytes())?;
+            Ok(IsNull::No)
+        }
+    }
+
+    impl<T> ToSql<sql_types::Uuid, Pg> for EntityId<T> {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+            <Uuid as ToSql<sql_types::Uuid, Pg>>::to_sql(&self.0, out)
+        }
+    }
+
+    impl<T> FromSql<sql_types::Uuid, Pg> for EntityId<T> {
+        fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+            <Uuid as FromSql<sql_types::Uuid, Pg>>::from_sql(value).map(Self::from_uuid)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use diesel::prelude::*;
+
+        use super::*;
+
+        struct Foo;
+
+        diesel::table! {
+            foos (id) {
+                id -> Uuid,
+            }
+        }
+
+        /// PostgreSQLの`uuid`型の列でエンティティIDを使用して検索できることを確認
+        #[test]
+        fn test_entity_id_postgres_filter() {
+            let id: EntityId<Foo> = EntityId::new();
+            let query = foos::table.filter(foos::id.eq(id)).select(foos::id);
+            let sql = diesel::debug_query::<Pg, _>(&query).to_string();
+            assert!(sql.contains(r#"WHERE ("foos"."id" = $1)"#), "{sql}");
+            assert!(sql.contains(&format!("{id:?}")), "{sql}");
+        }
+    }
+}
+
+/// SQLiteの`Text`型への書き込み
+#[cfg(feature = "diesel-sqlite")]
+mod sqlite {
+    use diesel::serialize::{self, IsNull, Output, ToSql};
+    use diesel::sql_types::Text;
+    use diesel::sqlite::Sqlite;
+
+    use crate::entity_id::EntityId;
+
+    /// エンティティIDをハイフン区切りのUUID文字列として書き込む。
+    impl<T> ToSql<Text, Sqlite> for EntityId<T> {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+            out.set_value(self.0.hyphenated().to_string());
+            Ok(IsNull::No)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "diesel-sqlite"))]
+mod tests {
+    use diesel::prelude::*;
+    use diesel::sqlite::SqliteConnection;
+
+    use super::*;
+
+    struct Foo;
+
+    diesel::table! {
+        foos (id) {
+            id -> Binary,
+            text_id -> Text,
+            parent_id -> Nullable<Binary>,
+        }
+    }
+
+    #[derive(Queryable)]
+    struct FooRow {
+        id: EntityId<Foo>,
+        text_id: EntityId<Foo>,
+        parent_id: Option<EntityId<Foo>>,
+    }
+
+    fn connection() -> SqliteConnection {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        diesel::sql_query(
+            "CREATE TABLE foos (id BLOB PRIMARY KEY, text_id TEXT NOT NULL, parent_id BLOB)",
+        )
+        .execute(&mut conn)
+        .unwrap();
+        conn
+    }
+
+    /// SQLiteの`BLOB`列及び`TEXT`列にエンティティIDを書き込み、検索して読み込めることを確認
+    #[test]
+    fn test_entity_id_sqlite_round_trip() {
+        let mut conn = connection();
+        let id: EntityId<Foo> = EntityId::new();
+        let parent_id: EntityId<Foo> = EntityId::new();
+        diesel::insert_into(foos::table)
+            .values((
+                foos::id.eq(id),
+                foos::text_id.eq(&id),
+                foos::parent_id.eq(Some(parent_id)),
+            ))
+            .execute(&mut conn)
+            .unwrap();
+
+        let row: FooRow = foos::table
+            .filter(foos::id.eq(id))
+            .filter(foos::text_id.eq(id))
+            .first(&mut conn)
+            .unwrap();
+        assert_eq!(id, row.id);
+        assert_eq!(id, row.text_id);
+        assert_eq!(Some(parent_id), row.parent_id);
+
+        let text: String = foos::table
+            .select(diesel::dsl::sql::<Text>("text_id"))
+            .first(&mut conn)
+            .unwrap();
+        assert_eq!(id.to_string(), text);
+    }
+
+    /// UUIDとして解釈できない値を読み込めないことを確認
+    #[test]
+    fn test_entity_id_sqlite_invalid() {
+        let mut conn = connection();
+        diesel::sql_query("INSERT INTO foos (id, text_id) VALUES (x'00', 'not-a-uuid')")
+            .execute(&mut conn)
+            .unwrap();
+        let result: QueryResult<EntityId<Foo>> = foos::table.select(foos::id).first(&mut conn);
+        assert!(result.is_err());
+        let result: QueryResult<EntityId<Foo>> = foos::table.select(foos::text_id).first(&mut conn);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,78 @@
+use fake::{Dummy, Faker, Rng};
+use uuid::Builder;
+
+use super::EntityId;
+
+/// 乱数生成器から、UUIDv4を使用したエンティティIDを生成する。
+///
+/// `id_generator::with_generator`によるIDジェネレーターの上書きは使用せず、与えられた乱数生成器のみを使用する。
+/// このため、シードを固定した乱数生成器を使用すると、同じエンティティIDを再現できる。
+///
+/// ```rust
+/// use domain_primitives::entity_id::EntityId;
+/// use fake::rand::SeedableRng;
+/// use fake::rand::rngs::StdRng;
+/// use fake::{Fake, Faker};
+///
+/// struct User;
+///
+/// let id1: EntityId<User> = Faker.fake_with_rng(&mut StdRng::seed_from_u64(42));
+/// let id2: EntityId<User> = Faker.fake_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert_eq!(id1, id2);
+/// ```
+impl<T> Dummy<Faker> for EntityId<T> {
+    fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, rng: &mut R) -> Self {
+        Self::from_uuid(Builder::from_random_bytes(rng.random()).into_uuid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fake::Fake;
+    use fake::rand::SeedableRng;
+    use fake::rand::rngs::StdRng;
+    use uuid::Uuid;
+
+    use super::*;
+
+    struct User;
+
+    struct Order {
+        id: EntityId<Order>,
+        user_id: EntityId<User>,
+    }
+
+    impl Dummy<Faker> for Order {
+        fn dummy_with_rng<R: Rng + ?Sized>(config: &Faker, rng: &mut R) -> Self {
+            Self {
+                id: config.fake_with_rng(rng),
+                user_id: config.fake_with_rng(rng),
+            }
+        }
+    }
+
+    /// UUIDv4を使用したエンティティIDを生成することを確認
+    #[test]
+    fn test_entity_id_fake() {
+        let id: EntityId<User> = Faker.fake();
+        assert_eq!(Some(uuid::Version::Random), id.to_uuid().get_version());
+        assert_ne!(Uuid::nil(), id.to_uuid());
+    }
+
+    /// シードを固定した乱数生成器で、同じエンティティIDを再現できることを確認
+    #[test]
+    fn test_entity_id_fake_seeded() {
+        let orders: Vec<Order> = fake::vec![Order; 3];
+        assert_eq!(3, orders.len());
+
+        let mut rng1 = StdRng::seed_from_u64(42);
+        let mut rng2 = StdRng::seed_from_u64(42);
+        for _ in 0..10 {
+            let order1: Order = Faker.fake_with_rng(&mut rng1);
+            let order2: Order = Faker.fake_with_rng(&mut rng2);
+            assert_eq!(order1.id, order2.id);
+            assert_eq!(order1.user_id, order2.user_id);
+            assert_ne!(order1.id.to_uuid(), order1.user_id.to_uuid());
+        }
+    }
+}
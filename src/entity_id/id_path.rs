@@ -0,0 +1,255 @@
+use std::ops::Deref;
+
+use super::{EntityId, ParseEntityIdError, entity_name, param_name};
+
+/// パスパラメーターからエンティティIDを取り出すエクストラクター
+///
+/// パスパラメーターは、次の順で探す。
+/// 名前の異なるパスパラメーターは、パスパラメーターが1つだけの場合でも使用しない。
+///
+/// 1. エンティティの型名をスネークケースにして`_id`を付けた名前（`User`の場合は`user_id`）
+/// 2. `id`
+///
+/// パスパラメーターが見つからない場合や、エンティティIDとして解析できない場合は、
+/// エンティティとパスパラメーターの名前を含む`application/problem+json`形式のレスポンスで拒否する。
+pub struct IdPath<T>(pub EntityId<T>);
+
+impl<T> IdPath<T> {
+    /// エンティティIDを返す。
+    pub fn into_inner(self) -> EntityId<T> {
+        self.0
+    }
+
+    /// パスパラメーターの名前と値の組から、エンティティIDを取り出す。
+    pub(crate) fn from_params<'a, I>(params: I) -> Result<Self, IdPathRejection>
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        let params: Vec<_> = params.into_iter().collect();
+        let name = param_name::<T>();
+        let found = params
+            .iter()
+            .find(|(key, _)| *key == name)
+            .or_else(|| params.iter().find(|(key, _)| *key == "id"));
+        let Some((parameter, value)) = found else {
+            return Err(IdPathRejection {
+                entity: entity_name::<T>(),
+                parameter: name,
+                reason: Reason::Missing,
+            });
+        };
+        value.parse().map(Self).map_err(|e| IdPathRejection {
+            entity: entity_name::<T>(),
+            parameter: parameter.to_string(),
+            reason: Reason::Invalid(Box::new(e)),
+        })
+    }
+
+    /// パスパラメーターがUTF-8として不正な場合の拒否の理由を返す。
+    ///
+    /// `detail`には、フレームワークが報告したエラーの内容を指定する。
+    #[cfg(feature = "axum")]
+    pub(crate) fn invalid_utf8(detail: impl std::fmt::Display) -> IdPathRejection {
+        IdPathRejection {
+            entity: entity_name::<T>(),
+            parameter: param_name::<T>(),
+            reason: Reason::InvalidUtf8(detail.to_string()),
+        }
+    }
+}
+
+impl<T> Deref for IdPath<T> {
+    type Target = EntityId<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> Clone for IdPath<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for IdPath<T> {}
+
+impl<T> std::fmt::Debug for IdPath<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("IdPath").field(&self.0).finish()
+    }
+}
+
+/// `IdPath`がリクエストを拒否した理由
+///
+/// パスパラメーターがUTF-8として不正な場合や、エンティティIDとして解析できない場合は
+/// `400 Bad Request`、ルーティングの設定の誤りでパスパラメーターが見つからない場合は
+/// `500 Internal Server Error`として、次のような`application/problem+json`形式のレスポンスを返す。
+///
+/// ```json
+/// {
+///   "type": "about:blank",
+///   "title": "Bad Request",
+///   "status": 400,
+///   "detail": "invalid entity id of `User` in path parameter `user_id`: invalid character: ...",
+///   "entity": "User",
+///   "parameter": "user_id"
+/// }
+/// ```
+#[derive(Debug)]
+pub struct IdPathRejection {
+    entity: String,
+    parameter: String,
+    reason: Reason,
+}
+
+/// 拒否の理由
+#[derive(Debug)]
+enum Reason {
+    /// パスパラメーターが見つからない
+    Missing,
+    /// パスパラメーターがUTF-8として不正
+    ///
+    /// `actix-web`は不正なUTF-8をパーセントエンコードしたまま渡すため、`axum`でのみ使用する。
+    #[cfg_attr(not(feature = "axum"), allow(dead_code))]
+    InvalidUtf8(String),
+    /// パスパラメーターをエンティティIDとして解析できない
+    Invalid(Box<ParseEntityIdError>),
+}
+
+impl IdPathRejection {
+    /// モジュールパスを除いたエンティティの型名を返す。
+    pub fn entity(&self) -> &str {
+        &self.entity
+    }
+
+    /// パスパラメーターの名前を返す。
+    ///
+    /// パスパラメーターが見つからない場合や、UTF-8として不正な場合は、探したパスパラメーターの名前を返す。
+    pub fn parameter(&self) -> &str {
+        &self.parameter
+    }
+
+    /// エンティティIDの解析エラーを返す。
+    ///
+    /// エンティティIDとして解析する前に拒否した場合は`None`を返す。
+    pub fn parse_error(&self) -> Option<&ParseEntityIdError> {
+        match &self.reason {
+            Reason::Invalid(e) => Some(e),
+            Reason::Missing | Reason::InvalidUtf8(_) => None,
+        }
+    }
+
+    /// レスポンスのHTTPステータスコードを返す。
+    pub fn status(&self) -> u16 {
+        self.status_as(400, 500)
+    }
+
+    /// 拒否の理由に応じて、`400 Bad Request`または`500 Internal Server Error`に対応する値を返す。
+    ///
+    /// フレームワークごとのステータスコードの型に変換する場合に使用する。
+    pub(crate) fn status_as<S>(&self, bad_request: S, internal_server_error: S) -> S {
+        match self.reason {
+            Reason::InvalidUtf8(_) | Reason::Invalid(_) => bad_request,
+            Reason::Missing => internal_server_error,
+        }
+    }
+
+    /// `application/problem+json`形式のレスポンスボディを返す。
+    pub(crate) fn problem_json(&self) -> String {
+        serde_json::json!({
+            "type": "about:blank",
+            "title": self.status_as("Bad Request", "Internal Server Error"),
+            "status": self.status(),
+            "detail": self.to_string(),
+            "entity": self.entity,
+            "parameter": self.parameter,
+        })
+        .to_string()
+    }
+}
+
+impl std::fmt::Display for IdPathRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.reason {
+            Reason::Invalid(e) => write!(
+                f,
+                "invalid entity id of `{}` in path parameter `{}`: {}",
+                self.entity,
+                self.parameter,
+                e.kind()
+            ),
+            Reason::InvalidUtf8(detail) => write!(
+                f,
+                "invalid path parameters for entity id of `{}`: {detail}",
+                self.entity
+            ),
+            Reason::Missing => write!(
+                f,
+                "missing path parameter `{}` for entity id of `{}`",
+                self.parameter, self.entity
+            ),
+        }
+    }
+}
+
+impl std::error::Error for IdPathRejection {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.parse_error().map(|e| e as _)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct OrderLine;
+
+    /// パスパラメーターを名前の優先順位に従って探すことを確認
+    #[test]
+    fn test_id_path_from_params() {
+        let id: EntityId<OrderLine> = EntityId::new();
+        let s = id.to_string();
+
+        let params = [("id", "x"), ("order_line_id", s.as_str())];
+        assert_eq!(id, *IdPath::from_params(params).unwrap());
+        let params = [("user_id", "x"), ("id", s.as_str())];
+        assert_eq!(id, *IdPath::from_params(params).unwrap());
+        let params = [("line", s.as_str())];
+        assert!(IdPath::<OrderLine>::from_params(params).is_err());
+    }
+
+    /// パスパラメーターが見つからない場合や、解析できない場合に拒否することを確認
+    #[test]
+    fn test_id_path_rejection() {
+        let err = IdPath::<OrderLine>::from_params([("a", "x"), ("b", "y")]).unwrap_err();
+        assert_eq!("OrderLine", err.entity());
+        assert_eq!("order_line_id", err.parameter());
+        assert!(err.parse_error().is_none());
+        assert_eq!(500, err.status());
+
+        let err = IdPath::<OrderLine>::from_params([("order_line_id", "x")]).unwrap_err();
+        assert_eq!("order_line_id", err.parameter());
+        assert_eq!("x", err.parse_error().unwrap().input());
+        assert_eq!(400, err.status());
+        let problem: serde_json::Value = serde_json::from_str(&err.problem_json()).unwrap();
+        assert_eq!("Bad Request", problem["title"]);
+        assert_eq!(400, problem["status"]);
+        assert_eq!("OrderLine", problem["entity"]);
+        assert_eq!("order_line_id", problem["parameter"]);
+        assert_eq!(err.to_string(), problem["detail"]);
+    }
+
+    /// パスパラメーターがUTF-8として不正な場合に`400 Bad Request`で拒否することを確認
+    #[cfg(feature = "axum")]
+    #[test]
+    fn test_id_path_rejection_invalid_utf8() {
+        let err = IdPath::<OrderLine>::invalid_utf8("Invalid UTF-8 in `order_line_id`");
+        assert_eq!("order_line_id", err.parameter());
+        assert!(err.parse_error().is_none());
+        assert_eq!(400, err.status());
+        let problem: serde_json::Value = serde_json::from_str(&err.problem_json()).unwrap();
+        assert_eq!("Bad Request", problem["title"]);
+        assert_eq!(400, problem["status"]);
+    }
+}
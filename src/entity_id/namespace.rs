@@ -0,0 +1,83 @@
+use uuid::Uuid;
+
+use super::EntityId;
+
+/// エンティティの名前空間
+///
+/// エンティティの型に実装すると、`EntityId::from_name`で、名前から決定的なUUIDv5を使用した
+/// エンティティIDを生成できる。
+/// 名前空間は型名やモジュールパスに依存しないため、型の名前を変えたり、モジュールを移動したりしても、
+/// 同じ名前から同じエンティティIDを生成できる。
+///
+/// ```rust
+/// use domain_primitives::entity_id::{EntityId, EntityNamespace};
+/// use uuid::{Uuid, uuid};
+///
+/// struct User;
+/// impl EntityNamespace for User {
+///     const NAMESPACE: Uuid = uuid!("0b0e4b1e-6a43-4f0e-9d5a-3f9a5e0c7d21");
+/// }
+///
+/// struct Order;
+/// impl EntityNamespace for Order {
+///     const NAMESPACE: Uuid = uuid!("5c1d8e6f-2b7a-4c39-8e14-9a6f0d3b2e58");
+/// }
+///
+/// assert_eq!(EntityId::<User>::from_name("abc"), EntityId::<User>::from_name("abc"));
+/// assert_ne!(
+///     EntityId::<User>::from_name("abc").to_uuid(),
+///     EntityId::<Order>::from_name("abc").to_uuid(),
+/// );
+/// ```
+pub trait EntityNamespace {
+    /// 名前空間
+    ///
+    /// エンティティの型ごとに、異なるUUIDを使用する。
+    const NAMESPACE: Uuid;
+}
+
+impl<T: EntityNamespace> EntityId<T> {
+    /// 名前から決定的なUUIDv5を使用してエンティティIDを生成する。
+    ///
+    /// 名前空間には、エンティティの型の`EntityNamespace::NAMESPACE`を使用する。
+    /// このため、同じ名前でも、エンティティの型の名前空間が異なればエンティティIDは異なる。
+    pub fn from_name(name: impl AsRef<[u8]>) -> Self {
+        Self::from_name_in(&T::NAMESPACE, name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct User;
+
+    impl EntityNamespace for User {
+        const NAMESPACE: Uuid = uuid::uuid!("0b0e4b1e-6a43-4f0e-9d5a-3f9a5e0c7d21");
+    }
+
+    struct Order;
+
+    impl EntityNamespace for Order {
+        const NAMESPACE: Uuid = uuid::uuid!("5c1d8e6f-2b7a-4c39-8e14-9a6f0d3b2e58");
+    }
+
+    /// 同じ名前から同じUUIDv5のエンティティIDが生成されることを確認
+    #[test]
+    fn test_entity_id_from_name() {
+        let id1: EntityId<User> = EntityId::from_name("abc");
+        let id2: EntityId<User> = EntityId::from_name(b"abc");
+        assert_eq!(id1, id2);
+        assert_eq!(Some(uuid::Version::Sha1), id1.to_uuid().get_version());
+        assert_eq!(Uuid::new_v5(&User::NAMESPACE, b"abc"), id1.to_uuid());
+        assert_ne!(id1, EntityId::from_name("abd"));
+    }
+
+    /// エンティティの名前空間が異なれば、同じ名前から異なるエンティティIDが生成されることを確認
+    #[test]
+    fn test_entity_id_from_name_per_entity_namespace() {
+        let user: EntityId<User> = EntityId::from_name("abc");
+        let order: EntityId<Order> = EntityId::from_name("abc");
+        assert_ne!(user.to_uuid(), order.to_uuid());
+    }
+}
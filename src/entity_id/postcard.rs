@@ -0,0 +1,52 @@
+use postcard::experimental::max_size::MaxSize;
+
+use super::EntityId;
+
+/// `postcard`でシリアライズしたエンティティIDの最大の大きさ
+///
+/// `postcard`は人間が読める形式ではないため、エンティティIDは長さ（1バイト）と16バイトのバイト列に
+/// シリアライズされる。
+impl<T> MaxSize for EntityId<T> {
+    const POSTCARD_MAX_SIZE: usize = 17;
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    struct User;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, MaxSize)]
+    struct Event {
+        user_id: EntityId<User>,
+        seq: u32,
+    }
+
+    /// エンティティIDをバイト列でシリアライズして、元に戻せることを確認
+    #[test]
+    fn test_entity_id_postcard_round_trip() {
+        let id: EntityId<User> = EntityId::new();
+        let bytes = postcard::to_allocvec(&id).unwrap();
+        assert_eq!(EntityId::<User>::POSTCARD_MAX_SIZE, bytes.len());
+        assert_eq!(16, bytes[0]);
+        assert_eq!(id.to_uuid().as_bytes().as_slice(), &bytes[1..]);
+
+        let event = Event {
+            user_id: id,
+            seq: 1,
+        };
+        let mut buf = [0u8; Event::POSTCARD_MAX_SIZE];
+        let bytes = postcard::to_slice(&event, &mut buf).unwrap();
+        assert_eq!(event, postcard::from_bytes(bytes).unwrap());
+    }
+
+    /// バイト列が16バイトでない場合にデシリアライズできないことを確認
+    #[test]
+    fn test_entity_id_postcard_invalid_length() {
+        let mut bytes = vec![15u8];
+        bytes.extend([0u8; 15]);
+        assert!(postcard::from_bytes::<EntityId<User>>(&bytes).is_err());
+    }
+}
@@ -0,0 +1,82 @@
+use std::error::Error;
+
+use bytes::BytesMut;
+use postgres_types::{FromSql, IsNull, ToSql, Type, accepts, to_sql_checked};
+use uuid::Uuid;
+
+use super::EntityId;
+
+/// エンティティIDをPostgreSQLの`uuid`型として書き込む。
+///
+/// `Vec<EntityId<T>>`は`uuid[]`型として書き込める。
+impl<T> ToSql for EntityId<T> {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        self.0.to_sql(ty, out)
+    }
+
+    accepts!(UUID);
+
+    to_sql_checked!();
+}
+
+/// PostgreSQLの`uuid`型からエンティティIDを読み込む。
+///
+/// `uuid[]`型は`Vec<EntityId<T>>`として読み込める。
+impl<'a, T> FromSql<'a> for EntityId<T> {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Uuid::from_sql(ty, raw).map(Self::from_uuid)
+    }
+
+    accepts!(UUID);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Foo;
+
+    /// `uuid`型のバイナリ形式で書き込んだエンティティIDを読み込めることを確認
+    #[test]
+    fn test_entity_id_postgres_types_round_trip() {
+        let id: EntityId<Foo> = EntityId::new();
+        let mut buf = BytesMut::new();
+        assert!(matches!(
+            id.to_sql(&Type::UUID, &mut buf).unwrap(),
+            IsNull::No
+        ));
+        assert_eq!(id.to_uuid().as_bytes().as_slice(), &buf[..]);
+        assert_eq!(id, EntityId::from_sql(&Type::UUID, &buf).unwrap());
+    }
+
+    /// `uuid[]`型のバイナリ形式で書き込んだエンティティIDの配列を読み込めることを確認
+    #[test]
+    fn test_entity_id_postgres_types_array_round_trip() {
+        let ids: Vec<EntityId<Foo>> = vec![EntityId::new(), EntityId::new()];
+        let mut buf = BytesMut::new();
+        ids.to_sql_checked(&Type::UUID_ARRAY, &mut buf).unwrap();
+        let uuids = Vec::<Uuid>::from_sql(&Type::UUID_ARRAY, &buf).unwrap();
+        assert_eq!(ids.iter().map(EntityId::to_uuid).collect::<Vec<_>>(), uuids);
+        assert_eq!(
+            ids,
+            Vec::<EntityId<Foo>>::from_sql(&Type::UUID_ARRAY, &buf).unwrap()
+        );
+    }
+
+    /// `uuid`型以外の型には書き込めないことを確認
+    #[test]
+    fn test_entity_id_postgres_types_wrong_type() {
+        let id: EntityId<Foo> = EntityId::new();
+        assert!(!<EntityId<Foo> as ToSql>::accepts(&Type::TEXT));
+        assert!(!<EntityId<Foo> as FromSql>::accepts(&Type::TEXT));
+        assert!(
+            id.to_sql_checked(&Type::TEXT, &mut BytesMut::new())
+                .is_err()
+        );
+        assert!(EntityId::<Foo>::from_sql(&Type::UUID, &[0; 15]).is_err());
+    }
+}
@@ -0,0 +1,158 @@
+#[cfg(feature = "alloc")]
+use alloc::format;
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+#[cfg(feature = "alloc")]
+use uuid::Uuid;
+
+#[cfg(feature = "alloc")]
+use super::{EntityId, ParseEntityIdError, ParseEntityIdErrorKind};
+#[cfg(feature = "alloc")]
+use crate::encoding::Encoding;
+
+/// プレフィックスとエンティティIDを符号化した文字列を区切る文字
+#[cfg(feature = "alloc")]
+const SEPARATOR: char = '_';
+
+/// エンティティのプレフィックス
+///
+/// エンティティの型に実装すると、`usr_01H8...`のようにエンティティの種類が分かる、
+/// プレフィックス付きの文字列でエンティティIDを表現できる。
+///
+/// ```rust
+/// # #[cfg(all(feature = "alloc", feature = "rng"))] {
+/// use domain_primitives::entity_id::{EntityId, EntityPrefix};
+///
+/// struct User;
+/// impl EntityPrefix for User {
+///     const PREFIX: &'static str = "usr";
+/// }
+///
+/// struct Order;
+/// impl EntityPrefix for Order {
+///     const PREFIX: &'static str = "ord";
+/// }
+///
+/// let id = EntityId::<User>::new();
+/// let s = id.to_prefixed_string();
+/// assert!(s.starts_with("usr_"));
+/// assert_eq!(id, EntityId::<User>::parse_prefixed(&s).unwrap());
+///
+/// let order_id = EntityId::<Order>::new().to_prefixed_string();
+/// assert!(EntityId::<User>::parse_prefixed(&order_id).is_err());
+/// # }
+/// ```
+pub trait EntityPrefix {
+    /// プレフィックス
+    ///
+    /// プレフィックスには、英数字と`_`を使用できる。
+    const PREFIX: &'static str;
+}
+
+#[cfg(feature = "alloc")]
+impl<T: EntityPrefix> EntityId<T> {
+    /// プレフィックス付きの文字列に変換する。
+    ///
+    /// プレフィックスの後に`_`を続け、UUIDのバイト列をCrockford Base32で符号化した26文字を続ける。
+    /// Crockford Base32はバイト列と同じ順序で並ぶため、UUIDv7を使用したエンティティIDは、
+    /// プレフィックス付きの文字列でも生成順に並ぶ。
+    pub fn to_prefixed_string(&self) -> String {
+        format!(
+            "{}{SEPARATOR}{}",
+            T::PREFIX,
+            self.encode(Encoding::Crockford32)
+        )
+    }
+
+    /// プレフィックス付きの文字列からエンティティIDを生成する。
+    ///
+    /// プレフィックスがエンティティの型のプレフィックスと一致しない場合はエラーを返す。
+    pub fn parse_prefixed(s: &str) -> Result<Self, ParseEntityIdError> {
+        let error = |kind| ParseEntityIdError::new::<T>(s.to_string(), kind);
+        let encoded = match s.rsplit_once(SEPARATOR) {
+            Some((prefix, encoded)) if prefix == T::PREFIX => encoded,
+            found => {
+                return Err(error(ParseEntityIdErrorKind::PrefixMismatch {
+                    expected: T::PREFIX,
+                    found: found.map(|(prefix, _)| prefix.to_string()),
+                }));
+            }
+        };
+        Encoding::Crockford32
+            .decode(encoded)
+            .map(|bytes| Self::from_uuid(Uuid::from_bytes(bytes)))
+            .map_err(|e| error(ParseEntityIdErrorKind::InvalidEncoding(e)))
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    struct User;
+
+    impl EntityPrefix for User {
+        const PREFIX: &'static str = "usr";
+    }
+
+    struct OrderLine;
+
+    impl EntityPrefix for OrderLine {
+        const PREFIX: &'static str = "order_line";
+    }
+
+    /// プレフィックス付きの文字列に変換して、元のエンティティIDに戻せることを確認
+    #[test]
+    fn test_prefixed_round_trip() {
+        let id: EntityId<User> = EntityId::from_uuid(Uuid::from_u128(1));
+        let s = id.to_prefixed_string();
+        assert_eq!("usr_00000000000000000000000001", s);
+        assert_eq!(id, EntityId::parse_prefixed(&s).unwrap());
+        assert_eq!(
+            id,
+            EntityId::parse_prefixed("usr_0000000000000000000000000l").unwrap()
+        );
+
+        let id: EntityId<OrderLine> =
+            EntityId::from_uuid(uuid::uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8"));
+        assert_eq!(
+            id,
+            EntityId::parse_prefixed(&id.to_prefixed_string()).unwrap()
+        );
+    }
+
+    /// プレフィックスが一致しない文字列を解析できないことを確認
+    #[test]
+    fn test_parse_prefixed_prefix_mismatch() {
+        let s = EntityId::<OrderLine>::from_uuid(Uuid::from_u128(1)).to_prefixed_string();
+        let err = EntityId::<User>::parse_prefixed(&s).unwrap_err();
+        assert_eq!(
+            &ParseEntityIdErrorKind::PrefixMismatch {
+                expected: "usr",
+                found: Some("order_line".to_string())
+            },
+            err.kind()
+        );
+        assert_eq!(s, err.input());
+
+        let err = EntityId::<User>::parse_prefixed("00000000000000000000000001").unwrap_err();
+        assert_eq!(
+            &ParseEntityIdErrorKind::PrefixMismatch {
+                expected: "usr",
+                found: None
+            },
+            err.kind()
+        );
+    }
+
+    /// 符号化された部分が不正な文字列を解析できないことを確認
+    #[test]
+    fn test_parse_prefixed_invalid_encoding() {
+        let err = EntityId::<User>::parse_prefixed("usr_0000000000000000000000000U").unwrap_err();
+        assert!(matches!(
+            err.kind(),
+            ParseEntityIdErrorKind::InvalidEncoding(_)
+        ));
+    }
+}
@@ -0,0 +1,213 @@
+use std::marker::PhantomData;
+
+use proptest::arbitrary::Arbitrary;
+use proptest::num::{u8, u128};
+use proptest::strategy::{NewTree, Strategy, ValueTree};
+use proptest::test_runner::TestRunner;
+use uuid::{Builder, Uuid};
+
+use super::{EntityId, entity_name};
+
+/// UUIDv4を使用したエンティティIDを生成するストラテジーを返す。
+pub fn v4<T>() -> EntityIdStrategy<T> {
+    EntityIdStrategy::new(Version::V4)
+}
+
+/// UUIDv7を使用したエンティティIDを生成するストラテジーを返す。
+///
+/// タイムスタンプは、UUIDv7で表現できる範囲から無作為に選ぶ。
+pub fn v7<T>() -> EntityIdStrategy<T> {
+    EntityIdStrategy::new(Version::V7)
+}
+
+/// 常にnil UUIDを使用したエンティティIDを生成するストラテジーを返す。
+pub fn nil<T>() -> EntityIdStrategy<T> {
+    EntityIdStrategy::new(Version::Nil)
+}
+
+/// UUIDv4、UUIDv7及びnil UUIDを使用したエンティティIDを生成するストラテジーを返す。
+///
+/// `any::<EntityId<T>>()`が使用するストラテジーで、nil UUIDは16回に1回程度生成する。
+pub fn any<T>() -> EntityIdStrategy<T> {
+    EntityIdStrategy::new(Version::Any)
+}
+
+/// 生成するUUIDのバージョン
+#[derive(Clone, Copy, Debug)]
+enum Version {
+    V4,
+    V7,
+    Nil,
+    Any,
+}
+
+/// エンティティIDを生成するストラテジー
+///
+/// 生成したエンティティIDは、UUIDを128ビットの整数とみなして、nil UUIDに向かって縮小する。
+/// このため、縮小の途中のエンティティIDは、UUIDのバージョンを保たない。
+///
+/// ```rust
+/// use domain_primitives::entity_id::EntityId;
+/// use proptest::prelude::*;
+///
+/// struct User;
+///
+/// proptest! {
+///     fn test_user_id(id in any::<EntityId<User>>(), v7 in domain_primitives::entity_id::proptest::v7::<User>()) {
+///         prop_assert_eq!(id, id.to_string().parse::<EntityId<User>>().unwrap());
+///         prop_assert!(v7.created_at().is_some());
+///     }
+/// }
+/// # test_user_id();
+/// ```
+pub struct EntityIdStrategy<T> {
+    version: Version,
+    _entity: PhantomData<fn() -> T>,
+}
+
+impl<T> EntityIdStrategy<T> {
+    fn new(version: Version) -> Self {
+        Self {
+            version,
+            _entity: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for EntityIdStrategy<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.version)
+    }
+}
+
+impl<T> std::fmt::Debug for EntityIdStrategy<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(&format!("EntityIdStrategy<{}>", entity_name::<T>()))
+            .field("version", &self.version)
+            .finish()
+    }
+}
+
+impl<T> Strategy for EntityIdStrategy<T> {
+    type Tree = EntityIdValueTree<T>;
+    type Value = EntityId<T>;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        let version = match self.version {
+            Version::Any => match u8::ANY.new_tree(runner)?.current() % 16 {
+                0 => Version::Nil,
+                1..=7 => Version::V4,
+                _ => Version::V7,
+            },
+            version => version,
+        };
+        let random = u128::ANY.new_tree(runner)?.current();
+        let uuid = match version {
+            Version::V4 => Builder::from_random_bytes(random.to_be_bytes()).into_uuid(),
+            Version::V7 => {
+                let [_, _, _, _, _, _, counter_random_bytes @ ..] = random.to_be_bytes();
+                let millis = (random >> 80) as u64;
+                Builder::from_unix_timestamp_millis(millis, &counter_random_bytes).into_uuid()
+            }
+            Version::Nil | Version::Any => Uuid::nil(),
+        };
+        Ok(EntityIdValueTree {
+            inner: u128::BinarySearch::new(uuid.as_u128()),
+            _entity: PhantomData,
+        })
+    }
+}
+
+/// `EntityIdStrategy`が生成する値の木
+pub struct EntityIdValueTree<T> {
+    inner: u128::BinarySearch,
+    _entity: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for EntityIdValueTree<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner,
+            _entity: PhantomData,
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for EntityIdValueTree<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple(&format!("EntityIdValueTree<{}>", entity_name::<T>()))
+            .field(&self.current())
+            .finish()
+    }
+}
+
+impl<T> ValueTree for EntityIdValueTree<T> {
+    type Value = EntityId<T>;
+
+    fn current(&self) -> Self::Value {
+        EntityId::from_uuid(Uuid::from_u128(self.inner.current()))
+    }
+
+    fn simplify(&mut self) -> bool {
+        self.inner.simplify()
+    }
+
+    fn complicate(&mut self) -> bool {
+        self.inner.complicate()
+    }
+}
+
+impl<T> Arbitrary for EntityId<T> {
+    type Parameters = ();
+    type Strategy = EntityIdStrategy<T>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::test_runner::Config;
+
+    use super::*;
+
+    struct User;
+
+    /// バージョンごとのストラテジーが、そのバージョンのUUIDを使用したエンティティIDを生成することを確認
+    #[test]
+    fn test_entity_id_strategy_version() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..100 {
+            let id = v4::<User>().new_tree(&mut runner).unwrap().current();
+            assert_eq!(Some(uuid::Version::Random), id.to_uuid().get_version());
+            let id = v7::<User>().new_tree(&mut runner).unwrap().current();
+            assert_eq!(Some(uuid::Version::SortRand), id.to_uuid().get_version());
+            let id = nil::<User>().new_tree(&mut runner).unwrap().current();
+            assert!(id.to_uuid().is_nil());
+        }
+
+        let versions: Vec<_> = (0..200)
+            .map(|_| {
+                let tree = any::<User>().new_tree(&mut runner).unwrap();
+                tree.current().to_uuid().get_version()
+            })
+            .collect();
+        assert!(versions.contains(&Some(uuid::Version::Random)));
+        assert!(versions.contains(&Some(uuid::Version::SortRand)));
+        assert!(versions.contains(&Some(uuid::Version::Nil)));
+    }
+
+    /// 生成したエンティティIDがnil UUIDに向かって縮小することを確認
+    #[test]
+    fn test_entity_id_strategy_shrink_to_nil() {
+        let mut runner = TestRunner::new(Config::default());
+        let result = runner.run(&proptest::arbitrary::any::<EntityId<User>>(), |_| {
+            Err(proptest::test_runner::TestCaseError::fail("always fails"))
+        });
+        match result {
+            Err(proptest::test_runner::TestError::Fail(_, id)) => assert!(id.to_uuid().is_nil()),
+            other => panic!("expected a failure, got {other:?}"),
+        }
+    }
+}
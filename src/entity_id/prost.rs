@@ -0,0 +1,117 @@
+use bytes::Bytes;
+
+use super::{EntityId, ParseEntityIdError};
+
+/// UUIDを表すProtocol Buffersのメッセージ
+///
+/// クレートの`proto/domain_primitives/uuid.proto`で定義する`domain_primitives.Uuid`メッセージに対応し、
+/// `value`にはUUIDの16バイトを格納する。
+/// `prost-build`でコードを生成する場合は、
+/// `extern_path(".domain_primitives.Uuid", "::domain_primitives::entity_id::prost::Uuid")`
+/// を指定すると、生成したコードでこの型を使用できる。
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Uuid {
+    /// UUIDの16バイト
+    #[prost(bytes = "vec", tag = "1")]
+    pub value: Vec<u8>,
+}
+
+impl<T> From<EntityId<T>> for Uuid {
+    fn from(value: EntityId<T>) -> Self {
+        Self {
+            value: value.0.as_bytes().to_vec(),
+        }
+    }
+}
+
+/// `Uuid`メッセージからエンティティIDを生成する。
+///
+/// `value`が16バイトでない場合はエラーを返す。
+impl<T> TryFrom<Uuid> for EntityId<T> {
+    type Error = ParseEntityIdError;
+
+    fn try_from(value: Uuid) -> Result<Self, Self::Error> {
+        Self::try_from(value.value.as_slice())
+    }
+}
+
+impl<T> TryFrom<&Uuid> for EntityId<T> {
+    type Error = ParseEntityIdError;
+
+    fn try_from(value: &Uuid) -> Result<Self, Self::Error> {
+        Self::try_from(value.value.as_slice())
+    }
+}
+
+/// エンティティIDを`bytes`フィールドに格納する16バイトに変換する。
+///
+/// `prost-build`で`bytes(["."])`を指定し、`bytes`フィールドを`Bytes`として生成した場合に使用する。
+impl<T> From<EntityId<T>> for Bytes {
+    fn from(value: EntityId<T>) -> Self {
+        Bytes::copy_from_slice(value.0.as_bytes())
+    }
+}
+
+/// `bytes`フィールドの値からエンティティIDを生成する。
+///
+/// 16バイトでない場合はエラーを返す。
+impl<T> TryFrom<Bytes> for EntityId<T> {
+    type Error = ParseEntityIdError;
+
+    fn try_from(value: Bytes) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+
+    use super::*;
+    use crate::entity_id::ParseEntityIdErrorKind;
+
+    struct User;
+
+    #[derive(Clone, PartialEq, Message)]
+    struct GetUserRequest {
+        #[prost(bytes = "vec", tag = "1")]
+        user_id: Vec<u8>,
+        #[prost(string, tag = "2")]
+        friend_id: String,
+        #[prost(message, optional, tag = "3")]
+        owner_id: Option<Uuid>,
+        #[prost(bytes = "bytes", tag = "4")]
+        group_id: Bytes,
+    }
+
+    /// `bytes`、`string`フィールド及び`Uuid`メッセージでエンティティIDを送受信できることを確認
+    #[test]
+    fn test_entity_id_prost_round_trip() {
+        let id: EntityId<User> = EntityId::new();
+        let request = GetUserRequest {
+            user_id: id.into(),
+            friend_id: id.into(),
+            owner_id: Some(id.into()),
+            group_id: id.into(),
+        };
+        let decoded = GetUserRequest::decode(request.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(request, decoded);
+
+        assert_eq!(id, EntityId::try_from(decoded.user_id).unwrap());
+        assert_eq!(id, EntityId::try_from(decoded.friend_id).unwrap());
+        assert_eq!(id, EntityId::try_from(&decoded.owner_id.unwrap()).unwrap());
+        assert_eq!(id, EntityId::try_from(decoded.group_id).unwrap());
+    }
+
+    /// 16バイトでない`bytes`フィールドからエンティティIDを生成できないことを確認
+    #[test]
+    fn test_entity_id_prost_invalid_length() {
+        let err = EntityId::<User>::try_from(vec![0u8; 15]).unwrap_err();
+        assert!(matches!(err.kind(), ParseEntityIdErrorKind::InvalidUuid(_)));
+        assert!(err.to_string().contains("expected 16 bytes"), "{err}");
+
+        let uuid = Uuid { value: vec![] };
+        assert!(EntityId::<User>::try_from(uuid).is_err());
+        assert!(EntityId::<User>::try_from(Bytes::from_static(&[0; 17])).is_err());
+    }
+}
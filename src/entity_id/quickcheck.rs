@@ -0,0 +1,69 @@
+use quickcheck::{Arbitrary, Gen};
+use uuid::{Builder, Uuid};
+
+use super::EntityId;
+
+/// UUIDv4、UUIDv7及びnil UUIDを使用したエンティティIDを、同じ割合で生成する。
+///
+/// 縮小する場合は、UUIDを128ビットの整数とみなして、nil UUIDに向かって縮小する。
+/// このため、縮小したエンティティIDは、UUIDのバージョンを保たない。
+impl<T: 'static> Arbitrary for EntityId<T> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let random = u128::arbitrary(g);
+        let uuid = match g.choose(&[4, 7, 0]) {
+            Some(4) => Builder::from_random_bytes(random.to_be_bytes()).into_uuid(),
+            Some(7) => {
+                let [_, _, _, _, _, _, counter_random_bytes @ ..] = random.to_be_bytes();
+                let millis = (random >> 80) as u64;
+                Builder::from_unix_timestamp_millis(millis, &counter_random_bytes).into_uuid()
+            }
+            _ => Uuid::nil(),
+        };
+        Self::from_uuid(uuid)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            self.0
+                .as_u128()
+                .shrink()
+                .map(|value| Self::from_uuid(Uuid::from_u128(value))),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::{QuickCheck, TestResult};
+
+    use super::*;
+
+    struct User;
+
+    /// UUIDv4、UUIDv7及びnil UUIDを使用したエンティティIDを生成することを確認
+    #[test]
+    fn test_entity_id_quickcheck_arbitrary() {
+        let mut g = Gen::new(100);
+        let versions: Vec<_> = (0..100)
+            .map(|_| EntityId::<User>::arbitrary(&mut g).to_uuid().get_version())
+            .collect();
+        assert!(versions.contains(&Some(uuid::Version::Random)));
+        assert!(versions.contains(&Some(uuid::Version::SortRand)));
+        assert!(versions.contains(&Some(uuid::Version::Nil)));
+    }
+
+    /// エンティティIDがnil UUIDに向かって縮小することを確認
+    #[test]
+    fn test_entity_id_quickcheck_shrink() {
+        let id: EntityId<User> = EntityId::new();
+        let shrunk: Vec<_> = id.shrink().collect();
+        assert!(shrunk.first().unwrap().to_uuid().is_nil());
+        assert!(shrunk.iter().all(|s| s < &id));
+        assert_eq!(0, EntityId::<User>::from_uuid(Uuid::nil()).shrink().count());
+
+        fn prop(id: EntityId<User>) -> TestResult {
+            TestResult::from_bool(id.to_string().parse::<EntityId<User>>().unwrap() == id)
+        }
+        QuickCheck::new().quickcheck(prop as fn(EntityId<User>) -> TestResult);
+    }
+}
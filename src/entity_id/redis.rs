@@ -0,0 +1,124 @@
+use redis::{FromRedisValue, ParsingError, RedisWrite, ToRedisArgs, ToSingleRedisArg, Value};
+
+use super::{EntityId, entity_name, snake_name};
+
+impl<T> EntityId<T> {
+    /// エンティティの型名で名前空間を付けたRedisのキーを返す。
+    ///
+    /// エンティティの型名をスネークケースにして、`:`で区切ってUUID文字列を付ける。
+    /// 例えば、`EntityId<OrderLine>`は`order_line:67e55044-10b1-426f-9247-bb680e5fe0c8`になる。
+    ///
+    /// ```rust
+    /// use domain_primitives::entity_id::EntityId;
+    ///
+    /// struct User;
+    ///
+    /// let id = EntityId::<User>::new();
+    /// assert_eq!(format!("user:{id}"), id.redis_key());
+    /// ```
+    pub fn redis_key(&self) -> String {
+        format!("{}:{}", snake_name::<T>(), self.0)
+    }
+}
+
+/// エンティティIDをハイフン区切りのUUID文字列として、Redisのコマンドの引数に書き込む。
+impl<T> ToRedisArgs for EntityId<T> {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        let mut buffer = uuid::Uuid::encode_buffer();
+        out.write_arg(self.0.hyphenated().encode_lower(&mut buffer).as_bytes());
+    }
+}
+
+impl<T> ToSingleRedisArg for EntityId<T> {}
+
+/// Redisの値からエンティティIDを生成する。
+///
+/// UUID文字列と16バイトのバイト列のどちらも受け付ける。
+impl<T> FromRedisValue for EntityId<T> {
+    fn from_redis_value_ref(v: &Value) -> Result<Self, ParsingError> {
+        let parsed = match v {
+            Value::BulkString(bytes) if bytes.len() == 16 => Self::try_from(bytes.as_slice()),
+            Value::BulkString(bytes) => match std::str::from_utf8(bytes) {
+                Ok(s) => s.parse(),
+                Err(_) => Self::try_from(bytes.as_slice()),
+            },
+            Value::SimpleString(s) | Value::VerbatimString { text: s, .. } => s.parse(),
+            _ => {
+                return Err(format!(
+                    "response type not compatible with entity id of `{}`: {v:?}",
+                    entity_name::<T>()
+                )
+                .into());
+            }
+        };
+        parsed.map_err(|e| e.to_string().into())
+    }
+
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        Self::from_redis_value_ref(&v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use redis::VerbatimFormat;
+    use uuid::Uuid;
+
+    use super::*;
+
+    struct OrderLine;
+
+    const UUID: Uuid = uuid::uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8");
+
+    /// エンティティIDをUUID文字列としてコマンドの引数に書き込むことを確認
+    #[test]
+    fn test_entity_id_to_redis_args() {
+        let id: EntityId<OrderLine> = EntityId::from_uuid(UUID);
+        assert_eq!(vec![UUID.to_string().into_bytes()], id.to_redis_args());
+        assert_eq!(
+            "order_line:67e55044-10b1-426f-9247-bb680e5fe0c8",
+            id.redis_key()
+        );
+    }
+
+    /// UUID文字列及び16バイトのバイト列からエンティティIDを生成できることを確認
+    #[test]
+    fn test_entity_id_from_redis_value() {
+        let id: EntityId<OrderLine> = EntityId::from_uuid(UUID);
+        let values = [
+            Value::BulkString(UUID.to_string().into_bytes()),
+            Value::BulkString(UUID.as_bytes().to_vec()),
+            Value::SimpleString(UUID.simple().to_string()),
+            Value::VerbatimString {
+                format: VerbatimFormat::Text,
+                text: UUID.to_string(),
+            },
+        ];
+        for value in values {
+            assert_eq!(id, EntityId::from_redis_value(value).unwrap());
+        }
+
+        let ids: Vec<EntityId<OrderLine>> =
+            redis::from_redis_value(Value::Array(vec![Value::BulkString(
+                UUID.to_string().into_bytes(),
+            )]))
+            .unwrap();
+        assert_eq!(vec![id], ids);
+        let id: Option<EntityId<OrderLine>> = redis::from_redis_value(Value::Nil).unwrap();
+        assert_eq!(None, id);
+    }
+
+    /// 不正な値からエンティティIDを生成できないことを確認
+    #[test]
+    fn test_entity_id_from_redis_value_error() {
+        let err =
+            EntityId::<OrderLine>::from_redis_value(Value::BulkString(b"x".to_vec())).unwrap_err();
+        assert!(err.to_string().contains("OrderLine"), "{err}");
+        let err = EntityId::<OrderLine>::from_redis_value(Value::Int(1)).unwrap_err();
+        assert!(err.to_string().contains("OrderLine"), "{err}");
+        assert!(EntityId::<OrderLine>::from_redis_value(Value::Nil).is_err());
+    }
+}
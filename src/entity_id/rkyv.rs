@@ -0,0 +1,215 @@
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+use rkyv::bytecheck::CheckBytes;
+use rkyv::rancor::Fallible;
+use rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+use uuid::Uuid;
+
+use super::{EntityId, entity_name};
+
+/// アーカイブされたエンティティID
+///
+/// エンティティIDを16バイトのバイト列としてアーカイブし、コピーせずに参照できる。
+/// アーカイブされたエンティティIDは、エンティティIDと比較できる。
+///
+/// ```rust
+/// use domain_primitives::entity_id::{ArchivedEntityId, EntityId};
+///
+/// struct User;
+///
+/// let id = EntityId::<User>::new();
+/// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&id).unwrap();
+/// let archived = rkyv::access::<ArchivedEntityId<User>, rkyv::rancor::Error>(&bytes).unwrap();
+/// assert_eq!(id, *archived);
+/// ```
+#[repr(transparent)]
+pub struct ArchivedEntityId<T> {
+    bytes: [u8; 16],
+    _entity: PhantomData<fn() -> T>,
+}
+
+impl<T> ArchivedEntityId<T> {
+    /// エンティティIDに変換する。
+    pub fn to_entity_id(&self) -> EntityId<T> {
+        EntityId::from_uuid(self.to_uuid())
+    }
+
+    /// UUIDに変換する。
+    pub fn to_uuid(&self) -> Uuid {
+        Uuid::from_bytes(self.bytes)
+    }
+}
+
+// SAFETY: `ArchivedEntityId`は`[u8; 16]`と同じレイアウトを持ち、内部可変性を持たない。
+unsafe impl<T> Portable for ArchivedEntityId<T> {}
+
+// SAFETY: `[u8; 16]`は、すべてのビットパターンが有効な値である。
+unsafe impl<T, C: Fallible + ?Sized> CheckBytes<C> for ArchivedEntityId<T> {
+    unsafe fn check_bytes(_: *const Self, _: &mut C) -> Result<(), C::Error> {
+        Ok(())
+    }
+}
+
+impl<T> Archive for EntityId<T> {
+    type Archived = ArchivedEntityId<T>;
+    type Resolver = ();
+
+    fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+        // SAFETY: `ArchivedEntityId`は`[u8; 16]`と同じレイアウトを持ち、未初期化のバイトを持たない。
+        unsafe {
+            out.write_unchecked(ArchivedEntityId {
+                bytes: self.0.into_bytes(),
+                _entity: PhantomData,
+            });
+        }
+    }
+}
+
+impl<T, S: Fallible + ?Sized> Serialize<S> for EntityId<T> {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<T, D: Fallible + ?Sized> Deserialize<EntityId<T>, D> for ArchivedEntityId<T> {
+    fn deserialize(&self, _: &mut D) -> Result<EntityId<T>, D::Error> {
+        Ok(self.to_entity_id())
+    }
+}
+
+impl<T> From<&ArchivedEntityId<T>> for EntityId<T> {
+    fn from(value: &ArchivedEntityId<T>) -> Self {
+        value.to_entity_id()
+    }
+}
+
+impl<T> std::fmt::Debug for ArchivedEntityId<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple(&format!("ArchivedEntityId<{}>", entity_name::<T>()))
+            .field(&self.to_uuid())
+            .finish()
+    }
+}
+
+impl<T> std::fmt::Display for ArchivedEntityId<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_uuid())
+    }
+}
+
+impl<T> PartialEq for ArchivedEntityId<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl<T> Eq for ArchivedEntityId<T> {}
+
+impl<T> PartialOrd for ArchivedEntityId<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for ArchivedEntityId<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.bytes.cmp(&other.bytes)
+    }
+}
+
+/// エンティティIDと同じハッシュ値を返す。
+impl<T> Hash for ArchivedEntityId<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_uuid().hash(state);
+    }
+}
+
+impl<T> PartialEq<EntityId<T>> for ArchivedEntityId<T> {
+    fn eq(&self, other: &EntityId<T>) -> bool {
+        self.bytes == *other.0.as_bytes()
+    }
+}
+
+impl<T> PartialEq<ArchivedEntityId<T>> for EntityId<T> {
+    fn eq(&self, other: &ArchivedEntityId<T>) -> bool {
+        other == self
+    }
+}
+
+impl<T> PartialOrd<EntityId<T>> for ArchivedEntityId<T> {
+    fn partial_cmp(&self, other: &EntityId<T>) -> Option<std::cmp::Ordering> {
+        Some(self.bytes.cmp(other.0.as_bytes()))
+    }
+}
+
+impl<T> PartialOrd<ArchivedEntityId<T>> for EntityId<T> {
+    fn partial_cmp(&self, other: &ArchivedEntityId<T>) -> Option<std::cmp::Ordering> {
+        Some(self.0.as_bytes().cmp(&other.bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::BuildHasher;
+
+    use rkyv::rancor::Error;
+
+    use super::*;
+
+    struct User;
+
+    #[derive(Debug, PartialEq, Archive, Serialize, Deserialize)]
+    struct Event {
+        user_id: EntityId<User>,
+        seq: u32,
+    }
+
+    /// エンティティIDをアーカイブして、コピーせずに参照及び復元できることを確認
+    #[test]
+    fn test_entity_id_rkyv_round_trip() {
+        let id: EntityId<User> = EntityId::new();
+        let bytes = rkyv::to_bytes::<Error>(&id).unwrap();
+        assert_eq!(id.to_uuid().as_bytes().as_slice(), bytes.as_slice());
+        let archived = rkyv::access::<ArchivedEntityId<User>, Error>(&bytes).unwrap();
+        assert_eq!(id, *archived);
+        assert_eq!(
+            id,
+            rkyv::deserialize::<EntityId<User>, Error>(archived).unwrap()
+        );
+
+        let event = Event {
+            user_id: id,
+            seq: 1,
+        };
+        let bytes = rkyv::to_bytes::<Error>(&event).unwrap();
+        let archived = rkyv::access::<ArchivedEvent, Error>(&bytes).unwrap();
+        assert_eq!(id, archived.user_id);
+        assert_eq!(event, rkyv::deserialize::<Event, Error>(archived).unwrap());
+    }
+
+    /// アーカイブされたエンティティIDをエンティティIDと比較できることを確認
+    #[test]
+    fn test_archived_entity_id_compare() {
+        let id1: EntityId<User> = EntityId::from_uuid(Uuid::from_u128(1));
+        let id2: EntityId<User> = EntityId::from_uuid(Uuid::from_u128(2));
+        let bytes = rkyv::to_bytes::<Error>(&id1).unwrap();
+        let archived = rkyv::access::<ArchivedEntityId<User>, Error>(&bytes).unwrap();
+
+        assert_eq!(*archived, id1);
+        assert_eq!(id1, *archived);
+        assert_ne!(*archived, id2);
+        assert_ne!(id2, *archived);
+        assert!(*archived < id2);
+        assert!(id2 > *archived);
+        assert_eq!(id1, EntityId::from(archived));
+        assert_eq!(id1.to_string(), archived.to_string());
+        assert_eq!(
+            format!("ArchivedEntityId<User>({id1})"),
+            format!("{archived:?}")
+        );
+
+        let state = std::hash::RandomState::new();
+        assert_eq!(state.hash_one(id1), state.hash_one(archived));
+    }
+}
@@ -0,0 +1,93 @@
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+use uuid::Uuid;
+
+use super::EntityId;
+
+/// エンティティIDを16バイトの`BLOB`として書き込む。
+///
+/// `TEXT`として書き込む場合は、`to_string`でUUID文字列に変換してからバインドする。
+impl<T> ToSql for EntityId<T> {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.0.as_bytes().as_slice()))
+    }
+}
+
+/// 16バイトの`BLOB`またはUUID文字列の`TEXT`からエンティティIDを読み込む。
+impl<T> FromSql for EntityId<T> {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let uuid = match value {
+            ValueRef::Blob(bytes) => {
+                Uuid::from_slice(bytes).map_err(|_| FromSqlError::InvalidBlobSize {
+                    expected_size: 16,
+                    blob_size: bytes.len(),
+                })?
+            }
+            ValueRef::Text(text) => {
+                Uuid::try_parse_ascii(text).map_err(|e| FromSqlError::Other(Box::new(e)))?
+            }
+            _ => return Err(FromSqlError::InvalidType),
+        };
+        Ok(Self::from_uuid(uuid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::Connection;
+
+    use super::*;
+
+    struct Foo;
+
+    fn connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute("CREATE TABLE foos (id BLOB, text_id TEXT)", ())
+            .unwrap();
+        conn
+    }
+
+    /// `BLOB`及び`TEXT`として書き込んだエンティティIDを読み込めることを確認
+    #[test]
+    fn test_entity_id_rusqlite_round_trip() {
+        let conn = connection();
+        let id: EntityId<Foo> = EntityId::new();
+        conn.execute(
+            "INSERT INTO foos (id, text_id) VALUES (?1, ?2)",
+            (id, id.to_string()),
+        )
+        .unwrap();
+
+        let (blob_id, text_id): (EntityId<Foo>, EntityId<Foo>) = conn
+            .query_row("SELECT id, text_id FROM foos WHERE id = ?1", [id], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+        assert_eq!(id, blob_id);
+        assert_eq!(id, text_id);
+        let blob: Vec<u8> = conn
+            .query_row("SELECT id FROM foos", (), |row| row.get(0))
+            .unwrap();
+        assert_eq!(id.to_uuid().as_bytes().as_slice(), blob);
+    }
+
+    /// UUIDとして解釈できない値を読み込めないことを確認
+    #[test]
+    fn test_entity_id_rusqlite_invalid() {
+        let conn = connection();
+        conn.execute(
+            "INSERT INTO foos (id, text_id) VALUES (x'00', 'not-a-uuid')",
+            (),
+        )
+        .unwrap();
+        let result = conn.query_row("SELECT id FROM foos", (), |row| {
+            row.get::<_, EntityId<Foo>>(0)
+        });
+        assert!(result.is_err());
+        let result = conn.query_row("SELECT text_id FROM foos", (), |row| {
+            row.get::<_, EntityId<Foo>>(0)
+        });
+        assert!(result.is_err());
+        let result = conn.query_row("SELECT 1", (), |row| row.get::<_, EntityId<Foo>>(0));
+        assert!(result.is_err());
+    }
+}
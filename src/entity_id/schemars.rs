@@ -0,0 +1,149 @@
+use std::borrow::Cow;
+
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+use super::{EXAMPLE_UUID, EntityId, EntityPrefix, entity_name};
+use crate::encoding::Encoding;
+
+/// エンティティIDのJSONスキーマ
+///
+/// エンティティIDは、`format`が`uuid`の文字列として表現する。
+/// エンコーディングを指定したり、プレフィックス付きの文字列でシリアライズしたりする場合は、
+/// このモジュールの関数を`#[schemars(schema_with = "...")]`に指定する。
+impl<T> JsonSchema for EntityId<T> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("EntityId")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "format": "uuid",
+            "examples": [EXAMPLE_UUID.to_string()],
+        })
+    }
+}
+
+/// エンコーディングを指定して、エンティティIDを符号化した文字列のJSONスキーマを返す。
+fn encoded_schema<T>(encoding: Encoding, name: &str) -> Schema {
+    let example = EntityId::<T>::from_uuid(EXAMPLE_UUID).encode(encoding);
+    json_schema!({
+        "type": "string",
+        "description": format!("The ID of `{}` encoded in {name}.", entity_name::<T>()),
+        "minLength": encoding.min_len(),
+        "maxLength": encoding.max_len(),
+        "examples": [example],
+    })
+}
+
+/// エンティティIDをBase58で符号化した文字列のJSONスキーマを返す。
+///
+/// `#[schemars(schema_with = "domain_primitives::entity_id::schemars::base58::<User>")]`のように使用する。
+pub fn base58<T>(_: &mut SchemaGenerator) -> Schema {
+    encoded_schema::<T>(Encoding::Base58, "Base58")
+}
+
+/// エンティティIDをBase62で符号化した文字列のJSONスキーマを返す。
+///
+/// `#[schemars(schema_with = "domain_primitives::entity_id::schemars::base62::<User>")]`のように使用する。
+pub fn base62<T>(_: &mut SchemaGenerator) -> Schema {
+    encoded_schema::<T>(Encoding::Base62, "Base62")
+}
+
+/// エンティティIDをCrockford Base32で符号化した文字列のJSONスキーマを返す。
+///
+/// `#[schemars(schema_with = "domain_primitives::entity_id::schemars::crockford32::<User>")]`のように使用する。
+pub fn crockford32<T>(_: &mut SchemaGenerator) -> Schema {
+    encoded_schema::<T>(Encoding::Crockford32, "Crockford Base32")
+}
+
+/// エンティティIDをURLセーフなBase64で符号化した文字列のJSONスキーマを返す。
+///
+/// `#[schemars(schema_with = "domain_primitives::entity_id::schemars::base64url::<User>")]`のように使用する。
+pub fn base64url<T>(_: &mut SchemaGenerator) -> Schema {
+    encoded_schema::<T>(Encoding::Base64Url, "URL-safe Base64")
+}
+
+/// エンティティIDをプレフィックス付きの文字列で表現したJSONスキーマを返す。
+///
+/// `#[schemars(schema_with = "domain_primitives::entity_id::schemars::prefixed::<User>")]`のように使用する。
+pub fn prefixed<T: EntityPrefix>(_: &mut SchemaGenerator) -> Schema {
+    json_schema!({
+        "type": "string",
+        "description": format!("The ID of `{}` prefixed with `{}_`.", entity_name::<T>(), T::PREFIX),
+        "pattern": format!("^{}_[0-9A-Za-z]{{26}}$", T::PREFIX),
+        "examples": [EntityId::<T>::from_uuid(EXAMPLE_UUID).to_prefixed_string()],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use schemars::schema_for;
+    use serde_json::json;
+
+    use super::*;
+
+    struct User;
+
+    impl EntityPrefix for User {
+        const PREFIX: &'static str = "usr";
+    }
+
+    #[allow(dead_code)]
+    #[derive(JsonSchema)]
+    struct Profile {
+        id: EntityId<User>,
+        friend_ids: Vec<EntityId<User>>,
+        #[schemars(schema_with = "base58::<User>")]
+        short_id: EntityId<User>,
+        #[schemars(schema_with = "prefixed::<User>")]
+        prefixed_id: EntityId<User>,
+    }
+
+    /// エンティティIDが`format`が`uuid`の文字列として表現されることを確認
+    #[test]
+    fn test_entity_id_json_schema() {
+        let schema = schema_for!(Profile);
+        let properties = &schema.as_value()["properties"];
+        assert_eq!(
+            json!({
+                "type": "string",
+                "format": "uuid",
+                "examples": ["67e55044-10b1-426f-9247-bb680e5fe0c8"],
+            }),
+            properties["id"]
+        );
+        assert_eq!(properties["id"], properties["friend_ids"]["items"]);
+        assert!(schema.get("$defs").is_none());
+    }
+
+    /// エンコーディング及びプレフィックスを指定したJSONスキーマを確認
+    #[test]
+    fn test_encoded_json_schema() {
+        let schema = schema_for!(Profile);
+        let properties = &schema.as_value()["properties"];
+        assert_eq!(
+            json!({
+                "type": "string",
+                "description": "The ID of `User` encoded in Base58.",
+                "minLength": 16,
+                "maxLength": 22,
+                "examples": ["Dq7QdGPZBdz9vwjm3jLQSB"],
+            }),
+            properties["short_id"]
+        );
+        assert_eq!(
+            json!({
+                "type": "string",
+                "description": "The ID of `User` prefixed with `usr_`.",
+                "pattern": "^usr_[0-9A-Za-z]{26}$",
+                "examples": ["usr_37WN84845H89QS4HXVD075ZR68"],
+            }),
+            properties["prefixed_id"]
+        );
+    }
+}
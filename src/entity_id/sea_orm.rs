@@ -0,0 +1,76 @@
+use sea_orm::sea_query::{ArrayType, ColumnType, Nullable, ValueType, ValueTypeErr};
+use sea_orm::{ColIdx, DbErr, QueryResult, TryFromU64, TryGetError, TryGetable, Value};
+use uuid::Uuid;
+
+use super::{EntityId, entity_name};
+
+impl<T> From<EntityId<T>> for Value {
+    fn from(id: EntityId<T>) -> Self {
+        id.0.into()
+    }
+}
+
+impl<T> TryGetable for EntityId<T> {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        Uuid::try_get_by(res, index).map(Self::from_uuid)
+    }
+}
+
+impl<T> ValueType for EntityId<T> {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        <Uuid as ValueType>::try_from(v).map(Self::from_uuid)
+    }
+
+    fn type_name() -> String {
+        format!("EntityId<{}>", entity_name::<T>())
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Uuid
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Uuid
+    }
+}
+
+impl<T> Nullable for EntityId<T> {
+    fn null() -> Value {
+        Value::Uuid(None)
+    }
+}
+
+/// エンティティIDは整数から変換できないため、常にエラーを返す。
+///
+/// エンティティIDを主キーに使用するモデルでは、`auto_increment = false`を指定する。
+impl<T> TryFromU64 for EntityId<T> {
+    fn try_from_u64(_: u64) -> Result<Self, DbErr> {
+        Err(DbErr::ConvertFromU64("EntityId"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Foo;
+
+    /// エンティティIDを`Value`に変換して、元のエンティティIDに戻せることを確認
+    #[test]
+    fn test_entity_id_sea_orm_value() {
+        let id: EntityId<Foo> = EntityId::new();
+        let value = Value::from(id);
+        assert_eq!(Value::Uuid(Some(Box::new(id.to_uuid()))), value);
+        assert_eq!(id, <EntityId<Foo> as ValueType>::try_from(value).unwrap());
+        assert!(<EntityId<Foo> as ValueType>::try_from(Value::Int(Some(1))).is_err());
+        assert!(<EntityId<Foo> as ValueType>::try_from(EntityId::<Foo>::null()).is_err());
+    }
+
+    /// エンティティIDの列の型がUUIDであることを確認
+    #[test]
+    fn test_entity_id_sea_orm_column_type() {
+        assert_eq!(ColumnType::Uuid, EntityId::<Foo>::column_type());
+        assert_eq!("EntityId<Foo>", EntityId::<Foo>::type_name());
+        assert!(EntityId::<Foo>::try_from_u64(1).is_err());
+    }
+}
@@ -0,0 +1,305 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use uuid::Uuid;
+
+use super::{EntityId, EntityPrefix};
+use crate::encoding::Encoding;
+
+/// エンティティIDをシリアライズする。
+///
+/// JSONなどの人間が読める形式では、ハイフン区切りのUUID文字列にシリアライズする。
+/// それ以外の形式では、16バイトのバイト列にシリアライズする。
+/// `bson`フィーチャーを有効にすると、`bson::Uuid`と同様に、BSONではサブタイプ4のバイナリにシリアライズする。
+/// `bson::Uuid`はUUIDの新しい型のラッパーとしてシリアライズするため、BSON以外の形式の出力は変わらない。
+impl<T> Serialize for EntityId<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        #[cfg(feature = "bson")]
+        return bson::Uuid::from(self.0).serialize(serializer);
+        #[cfg(not(feature = "bson"))]
+        self.0.serialize(serializer)
+    }
+}
+
+/// エンティティIDをデシリアライズする。
+///
+/// 人間が読める形式では、ハイフン区切り、シンプル、URN及び波括弧で囲まれた形式のUUID文字列を受け付ける。
+/// `bson`フィーチャーを有効にすると、`bson::Uuid`と同様に、BSONのサブタイプ4のバイナリも受け付ける。
+impl<'de, T> Deserialize<'de> for EntityId<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[cfg(feature = "bson")]
+        {
+            if deserializer.is_human_readable() {
+                return bson::Uuid::deserialize(deserializer)
+                    .map(|uuid| Self::from_uuid(uuid.into()));
+            }
+            // `bson::Uuid`は自己記述的な形式を前提とするため、バイト列を読み込む形式では、
+            // バイト列とBSONのバイナリのどちらも受け付けるビジターを使用する。
+            deserializer
+                .deserialize_bytes(bson_compat::CompactVisitor)
+                .map(Self::from_uuid)
+        }
+        #[cfg(not(feature = "bson"))]
+        Uuid::deserialize(deserializer).map(Self::from_uuid)
+    }
+}
+
+/// `bson`フィーチャーを有効にした場合のデシリアライズ
+#[cfg(feature = "bson")]
+mod bson_compat {
+    use serde::Deserialize;
+    use serde::de::value::MapAccessDeserializer;
+    use serde::de::{Error, MapAccess, SeqAccess, Visitor};
+
+    use super::Uuid;
+
+    /// 人間が読めない形式で、UUIDを読み込むビジター
+    ///
+    /// バイナリのBSONの読み込み（`bson::from_slice`）は、人間が読めない形式として扱われ、
+    /// バイナリを`$binary`のマップとして渡すため、16バイトのバイト列に加えてマップも受け付ける。
+    pub(super) struct CompactVisitor;
+
+    impl<'de> Visitor<'de> for CompactVisitor {
+        type Value = Uuid;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("16 bytes or a BSON binary")
+        }
+
+        fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            Uuid::from_slice(v).map_err(E::custom)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut bytes = [0u8; 16];
+            for (index, byte) in bytes.iter_mut().enumerate() {
+                *byte = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(index, &self))?;
+            }
+            Ok(Uuid::from_bytes(bytes))
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+            bson::Uuid::deserialize(MapAccessDeserializer::new(map)).map(Into::into)
+        }
+    }
+}
+
+/// エンコーディングを指定して、エンティティIDを符号化した文字列にシリアライズする。
+fn serialize_encoded<T, S>(
+    id: &EntityId<T>,
+    encoding: Encoding,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&id.encode(encoding))
+}
+
+/// エンコーディングを指定して、符号化した文字列からエンティティIDをデシリアライズする。
+fn deserialize_encoded<'de, T, D>(
+    encoding: Encoding,
+    deserializer: D,
+) -> Result<EntityId<T>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    EntityId::decode(&s, encoding).map_err(serde::de::Error::custom)
+}
+
+macro_rules! encoded_module {
+    ($(#[$attr:meta])* $name:ident, $encoding:expr) => {
+        $(#[$attr])*
+        pub mod $name {
+            use serde::{Deserializer, Serializer};
+
+            use super::{deserialize_encoded, serialize_encoded};
+            use crate::encoding::Encoding;
+            use crate::entity_id::EntityId;
+
+            /// エンティティIDをシリアライズする。
+            pub fn serialize<T, S>(id: &EntityId<T>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serialize_encoded(id, $encoding, serializer)
+            }
+
+            /// エンティティIDをデシリアライズする。
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<EntityId<T>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserialize_encoded($encoding, deserializer)
+            }
+        }
+    };
+}
+
+encoded_module!(
+    /// エンティティIDをBase58で符号化した文字列でシリアライズする。
+    ///
+    /// `#[serde(with = "domain_primitives::entity_id::serde::base58")]`のように使用する。
+    base58,
+    Encoding::Base58
+);
+
+encoded_module!(
+    /// エンティティIDをBase62で符号化した文字列でシリアライズする。
+    ///
+    /// `#[serde(with = "domain_primitives::entity_id::serde::base62")]`のように使用する。
+    base62,
+    Encoding::Base62
+);
+
+encoded_module!(
+    /// エンティティIDをCrockford Base32で符号化した文字列でシリアライズする。
+    ///
+    /// `#[serde(with = "domain_primitives::entity_id::serde::crockford32")]`のように使用する。
+    crockford32,
+    Encoding::Crockford32
+);
+
+encoded_module!(
+    /// エンティティIDをURLセーフなBase64で符号化した文字列でシリアライズする。
+    ///
+    /// `#[serde(with = "domain_primitives::entity_id::serde::base64url")]`のように使用する。
+    base64url,
+    Encoding::Base64Url
+);
+
+/// エンティティIDをプレフィックス付きの文字列でシリアライズする。
+///
+/// `#[serde(with = "domain_primitives::entity_id::serde::prefixed")]`のように使用する。
+pub mod prefixed {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::{EntityId, EntityPrefix};
+
+    /// エンティティIDをシリアライズする。
+    pub fn serialize<T, S>(id: &EntityId<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: EntityPrefix,
+        S: Serializer,
+    {
+        serializer.serialize_str(&id.to_prefixed_string())
+    }
+
+    /// エンティティIDをデシリアライズする。
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<EntityId<T>, D::Error>
+    where
+        T: EntityPrefix,
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        EntityId::parse_prefixed(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{Configure, Token, assert_tokens};
+
+    use super::*;
+
+    /// `Serialize`及び`Deserialize`を実装していない型をエンティティの型に使用できることを確認するための構造体
+    struct Foo;
+
+    const UUID: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+    /// JSONでハイフン区切りのUUID文字列にシリアライズされることを確認
+    #[test]
+    fn test_entity_id_serialize_json() {
+        let id: EntityId<Foo> = EntityId::from_uuid(Uuid::parse_str(UUID).unwrap());
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(format!("\"{UUID}\""), json);
+    }
+
+    /// JSONで様々な形式のUUID文字列からデシリアライズできることを確認
+    #[test]
+    fn test_entity_id_deserialize_json() {
+        let expected: EntityId<Foo> = EntityId::from_uuid(Uuid::parse_str(UUID).unwrap());
+        let inputs = [
+            format!("\"{UUID}\""),
+            "\"67e5504410b1426f9247bb680e5fe0c8\"".to_string(),
+            format!("\"urn:uuid:{UUID}\""),
+            format!("\"{{{UUID}}}\""),
+        ];
+        for input in inputs {
+            let id: EntityId<Foo> = serde_json::from_str(&input).unwrap();
+            assert_eq!(expected, id);
+        }
+    }
+
+    /// 人間が読める形式と読めない形式で、それぞれ文字列とバイト列にシリアライズされることを確認
+    #[test]
+    fn test_entity_id_serde_tokens() {
+        const BYTES: [u8; 16] = [
+            0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f,
+            0xe0, 0xc8,
+        ];
+        let id: EntityId<Foo> = EntityId::from_uuid(Uuid::from_bytes(BYTES));
+        #[cfg(not(feature = "bson"))]
+        {
+            assert_tokens(&id.readable(), &[Token::Str(UUID)]);
+            assert_tokens(&id.compact(), &[Token::Bytes(&BYTES)]);
+        }
+        #[cfg(feature = "bson")]
+        {
+            use serde_test::{assert_de_tokens, assert_ser_tokens};
+
+            let newtype = Token::NewtypeStruct {
+                name: "$__bson_private_uuid",
+            };
+            assert_tokens(&id.readable(), &[newtype, Token::Str(UUID)]);
+            assert_ser_tokens(&id.compact(), &[newtype, Token::Bytes(&BYTES)]);
+            assert_de_tokens(&id.compact(), &[Token::Bytes(&BYTES)]);
+        }
+    }
+
+    impl EntityPrefix for Foo {
+        const PREFIX: &'static str = "foo";
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Encoded {
+        #[serde(with = "crate::entity_id::serde::base58")]
+        base58: EntityId<Foo>,
+        #[serde(with = "crate::entity_id::serde::base64url")]
+        base64url: EntityId<Foo>,
+        #[serde(with = "crate::entity_id::serde::prefixed")]
+        prefixed: EntityId<Foo>,
+    }
+
+    /// シリアライズ時にエンコーディングを選択できることを確認
+    #[test]
+    fn test_entity_id_serde_with_encoding() {
+        let id: EntityId<Foo> = EntityId::from_uuid(Uuid::parse_str(UUID).unwrap());
+        let encoded = Encoded {
+            base58: id,
+            base64url: id,
+            prefixed: id,
+        };
+        let json = serde_json::to_string(&encoded).unwrap();
+        assert_eq!(
+            r#"{"base58":"Dq7QdGPZBdz9vwjm3jLQSB","base64url":"Z-VQRBCxQm-SR7toDl_gyA","prefixed":"foo_37WN84845H89QS4HXVD075ZR68"}"#,
+            json
+        );
+        assert_eq!(encoded, serde_json::from_str(&json).unwrap());
+    }
+
+    /// 符号化された文字列が不正な場合にデシリアライズできないことを確認
+    #[test]
+    fn test_entity_id_serde_with_encoding_error() {
+        let json = r#"{"base58":"1","base64url":"Z-VQRBCxQm-SR7toDl_gyA","prefixed":"foo_37WN84845H89QS4HXVD075ZR68"}"#;
+        let err = serde_json::from_str::<Encoded>(json).unwrap_err();
+        assert!(err.to_string().contains("invalid byte length"));
+    }
+}
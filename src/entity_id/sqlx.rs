@@ -0,0 +1,265 @@
+#[cfg(feature = "sqlx-sqlite")]
+use sqlx::error::BoxDynError;
+#[cfg(feature = "sqlx-sqlite")]
+use uuid::Uuid;
+
+#[cfg(feature = "sqlx-sqlite")]
+use super::EntityId;
+
+/// データベースから取得したバイト列をUUIDに変換する。
+///
+/// 16バイトの場合はUUIDのバイト列、それ以外の場合はUUID文字列とみなす。
+#[cfg(feature = "sqlx-sqlite")]
+fn uuid_from_db_bytes(bytes: &[u8]) -> Result<Uuid, BoxDynError> {
+    if bytes.len() == 16 {
+        Ok(Uuid::from_slice(bytes)?)
+    } else {
+        Ok(Uuid::try_parse_ascii(bytes)?)
+    }
+}
+
+/// PostgreSQLの`uuid`型との変換
+#[cfg(feature = "sqlx-postgres")]
+mod postgres {
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::postgres::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef};
+    use sqlx::{Decode, Encode, Postgres, Type};
+    use uuid::Uuid;
+
+    use crate::entity_id::EntityId;
+
+    impl<T> Type<Postgres> for EntityId<T> {
+        fn type_info() -> PgTypeInfo {
+            <Uuid as Type<Postgres>>::type_info()
+        }
+    }
+
+    impl<T> PgHasArrayType for EntityId<T> {
+        fn array_type_info() -> PgTypeInfo {
+            <Uuid as PgHasArrayType>::array_type_info()
+        }
+    }
+
+    impl<T> Encode<'_, Postgres> for EntityId<T> {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            <Uuid as Encode<Postgres>>::encode_by_ref(&self.0, buf)
+        }
+    }
+
+    impl<T> Decode<'_, Postgres> for EntityId<T> {
+        fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+            <Uuid as Decode<Postgres>>::decode(value).map(Self::from_uuid)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use sqlx::TypeInfo;
+
+        use super::*;
+
+        struct Foo;
+
+        /// PostgreSQLの`uuid`型及び`uuid[]`型として扱われることを確認
+        #[test]
+        fn test_entity_id_postgres_type() {
+            assert_eq!(
+                "UUID",
+                <EntityId<Foo> as Type<Postgres>>::type_info().name()
+            );
+            assert_eq!(
+                "UUID[]",
+                <EntityId<Foo> as PgHasArrayType>::array_type_info().name()
+            );
+            assert_eq!(
+                <Vec<Uuid> as Type<Postgres>>::type_info(),
+                <Vec<EntityId<Foo>> as Type<Postgres>>::type_info()
+            );
+        }
+    }
+}
+
+/// MySQLの`binary(16)`型との変換
+///
+/// エンティティIDは`binary(16)`として読み書きする。
+/// バインドする値からは列の型が分からず、`char(36)`の列にも16バイトのバイト列を書き込んでしまうため、
+/// 文字列型の列とは互換性がないものとして扱う。
+/// `char(36)`の列には、`uuid::fmt::Hyphenated`（`id.to_uuid().hyphenated()`）を使用する。
+#[cfg(feature = "sqlx-mysql")]
+mod mysql {
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::mysql::{MySqlTypeInfo, MySqlValueRef};
+    use sqlx::{Decode, Encode, MySql, Type};
+    use uuid::Uuid;
+
+    use crate::entity_id::EntityId;
+
+    impl<T> Type<MySql> for EntityId<T> {
+        fn type_info() -> MySqlTypeInfo {
+            <Uuid as Type<MySql>>::type_info()
+        }
+
+        /// バイナリの照合順序を持つ列（`binary`、`varbinary`及び`blob`）とだけ互換性がある。
+        fn compatible(ty: &MySqlTypeInfo) -> bool {
+            <&[u8] as Type<MySql>>::compatible(ty) && !<&str as Type<MySql>>::compatible(ty)
+        }
+    }
+
+    impl<T> Encode<'_, MySql> for EntityId<T> {
+        fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+            <Uuid as Encode<MySql>>::encode_by_ref(&self.0, buf)
+        }
+    }
+
+    impl<T> Decode<'_, MySql> for EntityId<T> {
+        fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+            <Uuid as Decode<MySql>>::decode(value).map(Self::from_uuid)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        struct Foo;
+
+        /// MySQLの`binary(16)`型としてだけ扱われることを確認
+        #[test]
+        fn test_entity_id_mysql_type() {
+            assert_eq!(
+                <Uuid as Type<MySql>>::type_info(),
+                <EntityId<Foo> as Type<MySql>>::type_info()
+            );
+            assert!(<EntityId<Foo> as Type<MySql>>::compatible(
+                &<&[u8] as Type<MySql>>::type_info()
+            ));
+            assert!(!<EntityId<Foo> as Type<MySql>>::compatible(
+                &<&str as Type<MySql>>::type_info()
+            ));
+        }
+
+        /// MySQLに16バイトのバイト列として書き込むことを確認
+        #[test]
+        fn test_entity_id_mysql_encode() {
+            let uuid = uuid::uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8");
+            let id: EntityId<Foo> = EntityId::from_uuid(uuid);
+            let mut buf = vec![];
+            let is_null = <EntityId<Foo> as Encode<MySql>>::encode_by_ref(&id, &mut buf).unwrap();
+            assert!(matches!(is_null, IsNull::No));
+            let mut expected = vec![16];
+            expected.extend_from_slice(uuid.as_bytes());
+            assert_eq!(expected, buf);
+        }
+    }
+}
+
+/// SQLiteの`BLOB`型及び`TEXT`型との変換
+///
+/// エンティティIDは`BLOB`として書き込み、`BLOB`と`TEXT`のどちらからも読み込める。
+#[cfg(feature = "sqlx-sqlite")]
+mod sqlite {
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::sqlite::{SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+    use sqlx::{Decode, Encode, Sqlite, Type};
+    use uuid::Uuid;
+
+    use super::{EntityId, uuid_from_db_bytes};
+
+    impl<T> Type<Sqlite> for EntityId<T> {
+        fn type_info() -> SqliteTypeInfo {
+            <Uuid as Type<Sqlite>>::type_info()
+        }
+
+        fn compatible(ty: &SqliteTypeInfo) -> bool {
+            <&[u8] as Type<Sqlite>>::compatible(ty) || <&str as Type<Sqlite>>::compatible(ty)
+        }
+    }
+
+    impl<'q, T> Encode<'q, Sqlite> for EntityId<T> {
+        fn encode_by_ref(
+            &self,
+            args: &mut Vec<SqliteArgumentValue<'q>>,
+        ) -> Result<IsNull, BoxDynError> {
+            <Uuid as Encode<Sqlite>>::encode_by_ref(&self.0, args)
+        }
+    }
+
+    impl<T> Decode<'_, Sqlite> for EntityId<T> {
+        fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+            let bytes = <&[u8] as Decode<Sqlite>>::decode(value)?;
+            uuid_from_db_bytes(bytes).map(Self::from_uuid)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use sqlx::{Connection, SqliteConnection};
+
+        use super::*;
+
+        struct Foo;
+
+        /// SQLiteの`BLOB`列に書き込んだエンティティIDを読み込めることを確認
+        #[tokio::test]
+        async fn test_entity_id_sqlite_blob() {
+            let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+            sqlx::query("CREATE TABLE foos (id BLOB PRIMARY KEY)")
+                .execute(&mut conn)
+                .await
+                .unwrap();
+            let id: EntityId<Foo> = EntityId::new();
+            sqlx::query("INSERT INTO foos (id) VALUES (?)")
+                .bind(id)
+                .execute(&mut conn)
+                .await
+                .unwrap();
+
+            let (fetched,): (EntityId<Foo>,) = sqlx::query_as("SELECT id FROM foos WHERE id = ?")
+                .bind(id)
+                .fetch_one(&mut conn)
+                .await
+                .unwrap();
+            assert_eq!(id, fetched);
+            let (bytes,): (Vec<u8>,) = sqlx::query_as("SELECT id FROM foos")
+                .fetch_one(&mut conn)
+                .await
+                .unwrap();
+            assert_eq!(id.to_uuid().as_bytes().as_slice(), bytes);
+        }
+
+        /// SQLiteの`TEXT`列に書き込んだUUID文字列をエンティティIDとして読み込めることを確認
+        #[tokio::test]
+        async fn test_entity_id_sqlite_text() {
+            let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+            sqlx::query("CREATE TABLE foos (id TEXT PRIMARY KEY)")
+                .execute(&mut conn)
+                .await
+                .unwrap();
+            let id: EntityId<Foo> = EntityId::new();
+            sqlx::query("INSERT INTO foos (id) VALUES (?)")
+                .bind(id.to_string())
+                .execute(&mut conn)
+                .await
+                .unwrap();
+
+            let (fetched,): (EntityId<Foo>,) = sqlx::query_as("SELECT id FROM foos")
+                .fetch_one(&mut conn)
+                .await
+                .unwrap();
+            assert_eq!(id, fetched);
+        }
+
+        /// UUIDとして解釈できない値を読み込めないことを確認
+        #[tokio::test]
+        async fn test_entity_id_sqlite_invalid() {
+            let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+            let result: Result<(EntityId<Foo>,), _> = sqlx::query_as("SELECT 'not-a-uuid'")
+                .fetch_one(&mut conn)
+                .await;
+            assert!(result.is_err());
+        }
+    }
+}
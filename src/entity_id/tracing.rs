@@ -0,0 +1,146 @@
+use tracing::Span;
+
+use super::{EntityId, param_name};
+
+impl<T> EntityId<T> {
+    /// 現在のスパンに、エンティティIDを記録する。
+    ///
+    /// フィールドの名前は、エンティティの型名をスネークケースにして`_id`を付けた名前（`User`の場合は`user_id`）
+    /// で、値はUUID文字列になる。
+    /// スパンは、`tracing::field::Empty`でそのフィールドを宣言しておく必要がある。
+    /// フィールドを宣言していない場合は、何も記録しない。
+    ///
+    /// ```rust
+    /// use domain_primitives::entity_id::EntityId;
+    ///
+    /// struct User;
+    ///
+    /// let span = tracing::info_span!("sign_in", user_id = tracing::field::Empty);
+    /// let _guard = span.enter();
+    /// let id = EntityId::<User>::new();
+    /// id.record_in_current_span();
+    /// ```
+    pub fn record_in_current_span(&self) {
+        self.record_in(&Span::current());
+    }
+
+    /// スパンに、エンティティIDを記録する。
+    ///
+    /// フィールドの名前と値は、`record_in_current_span`と同じである。
+    pub fn record_in(&self, span: &Span) {
+        span.record(param_name::<T>().as_str(), tracing::field::display(self));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+    use tracing_core::span::Current;
+
+    use super::*;
+
+    struct OrderLine;
+
+    /// スパンの名前、フィールドの名前及び値の組
+    type Fields = Mutex<Vec<(&'static str, String, String)>>;
+
+    /// スパンに記録されたフィールドを、スパンの名前と一緒に集めるサブスクライバー
+    ///
+    /// 入っているスパンをスタックで管理し、最後に入ったスパンを現在のスパンとする。
+    #[derive(Clone, Default)]
+    struct Recorder {
+        next_id: Arc<AtomicU64>,
+        spans: Arc<Mutex<Vec<&'static Metadata<'static>>>>,
+        stack: Arc<Mutex<Vec<Id>>>,
+        fields: Arc<Fields>,
+    }
+
+    impl Recorder {
+        fn metadata(&self, id: &Id) -> &'static Metadata<'static> {
+            self.spans.lock().unwrap()[id.into_u64() as usize - 1]
+        }
+    }
+
+    /// スパンの名前を付けて、フィールドを集める。
+    struct SpanVisitor<'a> {
+        span: &'static str,
+        fields: &'a Fields,
+    }
+
+    impl Visit for SpanVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            let mut fields = self.fields.lock().unwrap();
+            fields.push((self.span, field.name().to_string(), format!("{value:?}")));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+            self.spans.lock().unwrap().push(attrs.metadata());
+            Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+        }
+
+        fn record(&self, id: &Id, values: &Record<'_>) {
+            values.record(&mut SpanVisitor {
+                span: self.metadata(id).name(),
+                fields: &self.fields,
+            });
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, _: &Event<'_>) {}
+
+        fn enter(&self, id: &Id) {
+            self.stack.lock().unwrap().push(id.clone());
+        }
+
+        fn exit(&self, id: &Id) {
+            let mut stack = self.stack.lock().unwrap();
+            if let Some(index) = stack.iter().rposition(|entered| entered == id) {
+                stack.remove(index);
+            }
+        }
+
+        fn current_span(&self) -> Current {
+            match self.stack.lock().unwrap().last() {
+                Some(id) => Current::new(id.clone(), self.metadata(id)),
+                None => Current::none(),
+            }
+        }
+    }
+
+    /// 入っているスパンに、エンティティの型名から決めたフィールドでエンティティIDを記録することを確認
+    #[test]
+    fn test_entity_id_record_in_current_span() {
+        let recorder = Recorder::default();
+        let id: EntityId<OrderLine> = EntityId::new();
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let order = tracing::info_span!("order", order_line_id = tracing::field::Empty);
+            order.in_scope(|| {
+                // 作成しただけで入っていないスパンは、現在のスパンにならない。
+                let _created =
+                    tracing::info_span!("created", order_line_id = tracing::field::Empty);
+                id.record_in_current_span();
+            });
+            // スパンから出た後は、記録しない。
+            id.record_in_current_span();
+
+            let undeclared = tracing::info_span!("undeclared");
+            id.record_in(&undeclared);
+        });
+        assert_eq!(
+            vec![("order", "order_line_id".to_string(), id.to_string())],
+            *recorder.fields.lock().unwrap()
+        );
+    }
+}
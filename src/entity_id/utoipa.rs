@@ -0,0 +1,228 @@
+use std::borrow::Cow;
+
+use utoipa::openapi::path::{Parameter, ParameterBuilder, ParameterIn};
+use utoipa::openapi::schema::{KnownFormat, ObjectBuilder, Schema, SchemaFormat, Type};
+use utoipa::openapi::{RefOr, Required};
+use utoipa::{IntoParams, PartialSchema, ToSchema};
+
+use super::{EXAMPLE_UUID, EntityId, EntityPrefix, entity_name, param_name};
+use crate::encoding::Encoding;
+
+/// エンティティIDのOpenAPIスキーマ
+///
+/// エンティティIDは、`format`が`uuid`の文字列として表現する。
+/// エンコーディングを指定したり、プレフィックス付きの文字列でシリアライズしたりする場合は、
+/// このモジュールの関数を`#[schema(schema_with = ...)]`に指定する。
+///
+/// `utoipa`の`ToSchema`を導出する構造体のフィールドでは、`EntityId<User>`のようにジェネリック型を
+/// 直接指定すると、エンティティの型にも`ToSchema`の実装が要求される。
+/// `type UserId = EntityId<User>;`のような型エイリアスを使用すると、エンティティの型に`ToSchema`を
+/// 実装する必要はなく、`UserId`という名前のスキーマとして参照される。
+impl<T> PartialSchema for EntityId<T> {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .format(Some(SchemaFormat::KnownFormat(KnownFormat::Uuid)))
+            .examples([EXAMPLE_UUID.to_string()])
+            .into()
+    }
+}
+
+impl<T> ToSchema for EntityId<T> {
+    fn name() -> Cow<'static, str> {
+        let mut name: String = entity_name::<T>()
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+            .collect();
+        name.push_str("Id");
+        Cow::Owned(name)
+    }
+}
+
+/// パスパラメーターとしてのエンティティID
+///
+/// パラメーター名は、エンティティの型名をスネークケースにして`_id`を付けたもの（`User`の場合は`user_id`）
+/// とする。
+impl<T> IntoParams for EntityId<T> {
+    fn into_params(parameter_in_provider: impl Fn() -> Option<ParameterIn>) -> Vec<Parameter> {
+        vec![
+            ParameterBuilder::new()
+                .name(param_name::<T>())
+                .parameter_in(parameter_in_provider().unwrap_or(ParameterIn::Path))
+                .required(Required::True)
+                .description(Some(format!("The ID of `{}`.", entity_name::<T>())))
+                .schema(Some(Self::schema()))
+                .build(),
+        ]
+    }
+}
+
+/// エンコーディングを指定して、エンティティIDを符号化した文字列のOpenAPIスキーマを返す。
+fn encoded_schema<T>(encoding: Encoding, name: &str) -> Schema {
+    let example = EntityId::<T>::from_uuid(EXAMPLE_UUID).encode(encoding);
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .description(Some(format!(
+            "The ID of `{}` encoded in {name}.",
+            entity_name::<T>()
+        )))
+        .min_length(Some(encoding.min_len()))
+        .max_length(Some(encoding.max_len()))
+        .examples([example])
+        .into()
+}
+
+/// エンティティIDをBase58で符号化した文字列のOpenAPIスキーマを返す。
+///
+/// `#[schema(schema_with = domain_primitives::entity_id::utoipa::base58::<User>)]`のように使用する。
+pub fn base58<T>() -> Schema {
+    encoded_schema::<T>(Encoding::Base58, "Base58")
+}
+
+/// エンティティIDをBase62で符号化した文字列のOpenAPIスキーマを返す。
+///
+/// `#[schema(schema_with = domain_primitives::entity_id::utoipa::base62::<User>)]`のように使用する。
+pub fn base62<T>() -> Schema {
+    encoded_schema::<T>(Encoding::Base62, "Base62")
+}
+
+/// エンティティIDをCrockford Base32で符号化した文字列のOpenAPIスキーマを返す。
+///
+/// `#[schema(schema_with = domain_primitives::entity_id::utoipa::crockford32::<User>)]`のように使用する。
+pub fn crockford32<T>() -> Schema {
+    encoded_schema::<T>(Encoding::Crockford32, "Crockford Base32")
+}
+
+/// エンティティIDをURLセーフなBase64で符号化した文字列のOpenAPIスキーマを返す。
+///
+/// `#[schema(schema_with = domain_primitives::entity_id::utoipa::base64url::<User>)]`のように使用する。
+pub fn base64url<T>() -> Schema {
+    encoded_schema::<T>(Encoding::Base64Url, "URL-safe Base64")
+}
+
+/// エンティティIDをプレフィックス付きの文字列で表現したOpenAPIスキーマを返す。
+///
+/// `#[schema(schema_with = domain_primitives::entity_id::utoipa::prefixed::<User>)]`のように使用する。
+pub fn prefixed<T: EntityPrefix>() -> Schema {
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .description(Some(format!(
+            "The ID of `{}` prefixed with `{}_`.",
+            entity_name::<T>(),
+            T::PREFIX
+        )))
+        .pattern(Some(format!("^{}_[0-9A-Za-z]{{26}}$", T::PREFIX)))
+        .examples([EntityId::<T>::from_uuid(EXAMPLE_UUID).to_prefixed_string()])
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use utoipa::OpenApi;
+
+    use super::*;
+
+    struct User;
+
+    impl EntityPrefix for User {
+        const PREFIX: &'static str = "usr";
+    }
+
+    struct OrderLine;
+
+    type UserId = EntityId<User>;
+
+    #[allow(dead_code)]
+    #[derive(ToSchema)]
+    struct Profile {
+        id: UserId,
+        friend_ids: Vec<UserId>,
+        #[schema(inline)]
+        inline_id: UserId,
+        #[schema(schema_with = base58::<User>)]
+        short_id: UserId,
+        #[schema(schema_with = prefixed::<User>)]
+        prefixed_id: EntityId<User>,
+    }
+
+    #[utoipa::path(get, path = "/users/{user_id}", params(EntityId<User>))]
+    #[allow(dead_code)]
+    fn get_user() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_user), components(schemas(Profile)))]
+    struct ApiDoc;
+
+    fn api_doc() -> serde_json::Value {
+        serde_json::to_value(ApiDoc::openapi()).unwrap()
+    }
+
+    /// エンティティIDが`format`が`uuid`の文字列として表現されることを確認
+    #[test]
+    fn test_entity_id_schema() {
+        let doc = api_doc();
+        let schemas = &doc["components"]["schemas"];
+        let expected = json!({
+            "type": "string",
+            "format": "uuid",
+            "examples": ["67e55044-10b1-426f-9247-bb680e5fe0c8"],
+        });
+        assert_eq!(expected, schemas["UserId"]);
+        let properties = &schemas["Profile"]["properties"];
+        let reference = json!({ "$ref": "#/components/schemas/UserId" });
+        assert_eq!(reference, properties["id"]);
+        assert_eq!(reference, properties["friend_ids"]["items"]);
+        assert_eq!(expected, properties["inline_id"]);
+    }
+
+    /// エンコーディング及びプレフィックスを指定したOpenAPIスキーマを確認
+    #[test]
+    fn test_encoded_schema() {
+        let doc = api_doc();
+        let properties = &doc["components"]["schemas"]["Profile"]["properties"];
+        assert_eq!(
+            json!({
+                "type": "string",
+                "description": "The ID of `User` encoded in Base58.",
+                "minLength": 16,
+                "maxLength": 22,
+                "examples": ["Dq7QdGPZBdz9vwjm3jLQSB"],
+            }),
+            properties["short_id"]
+        );
+        assert_eq!(
+            json!({
+                "type": "string",
+                "description": "The ID of `User` prefixed with `usr_`.",
+                "pattern": "^usr_[0-9A-Za-z]{26}$",
+                "examples": ["usr_37WN84845H89QS4HXVD075ZR68"],
+            }),
+            properties["prefixed_id"]
+        );
+    }
+
+    /// エンティティIDがパスパラメーターとして表現されることを確認
+    #[test]
+    fn test_entity_id_into_params() {
+        let doc = api_doc();
+        assert_eq!(
+            json!([{
+                "name": "user_id",
+                "in": "path",
+                "description": "The ID of `User`.",
+                "required": true,
+                "schema": {
+                    "type": "string",
+                    "format": "uuid",
+                    "examples": ["67e55044-10b1-426f-9247-bb680e5fe0c8"],
+                },
+            }]),
+            doc["paths"]["/users/{user_id}"]["get"]["parameters"]
+        );
+
+        let params = EntityId::<OrderLine>::into_params(|| Some(ParameterIn::Query));
+        assert_eq!("order_line_id", params[0].name);
+        assert!(matches!(params[0].parameter_in, ParameterIn::Query));
+    }
+}
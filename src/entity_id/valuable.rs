@@ -0,0 +1,77 @@
+use valuable::{Fields, NamedField, NamedValues, StructDef, Structable, Valuable, Value, Visit};
+
+use super::{EntityId, entity_name};
+
+/// `EntityId`の構造のフィールド
+static FIELDS: &[NamedField<'static>] = &[NamedField::new("entity"), NamedField::new("id")];
+
+/// エンティティIDを、エンティティの型名とUUID文字列をフィールドに持つ構造として記録する。
+///
+/// `tracing`の`valuable`による記録を有効にしている場合は、
+/// `tracing::info!(user = tracing::field::valuable(&id))`のように、
+/// `{ entity: "User", id: "67e55044-10b1-426f-9247-bb680e5fe0c8" }`を記録できる。
+impl<T> Valuable for EntityId<T> {
+    fn as_value(&self) -> Value<'_> {
+        Value::Structable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn Visit) {
+        let entity = entity_name::<T>();
+        let id = self.0.to_string();
+        visit.visit_named_fields(&NamedValues::new(
+            FIELDS,
+            &[Value::String(&entity), Value::String(&id)],
+        ));
+    }
+}
+
+impl<T> Structable for EntityId<T> {
+    fn definition(&self) -> StructDef<'_> {
+        StructDef::new_static("EntityId", Fields::Named(FIELDS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct User;
+
+    #[derive(Default)]
+    struct Collector(Vec<(String, String)>);
+
+    impl Visit for Collector {
+        fn visit_value(&mut self, value: Value<'_>) {
+            if let Value::Structable(s) = value {
+                s.visit(self);
+            }
+        }
+
+        fn visit_named_fields(&mut self, named_values: &NamedValues<'_>) {
+            for (field, value) in named_values {
+                let value = value.as_str().unwrap().to_string();
+                self.0.push((field.name().to_string(), value));
+            }
+        }
+    }
+
+    /// エンティティIDを、エンティティの型名とUUID文字列を持つ構造として記録することを確認
+    #[test]
+    fn test_entity_id_valuable() {
+        let id: EntityId<User> = EntityId::new();
+        let Value::Structable(s) = id.as_value() else {
+            panic!("expected a structable");
+        };
+        assert_eq!("EntityId", s.definition().name());
+
+        let mut collector = Collector::default();
+        valuable::visit(&id, &mut collector);
+        assert_eq!(
+            vec![
+                ("entity".to_string(), "User".to_string()),
+                ("id".to_string(), id.to_string()),
+            ],
+            collector.0
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use js_sys::{JsString, Uint8Array};
+use wasm_bindgen::{JsCast, JsError, JsValue};
+
+use super::{EntityId, ParseEntityIdError, entity_name};
+
+impl<T> EntityId<T> {
+    /// ハイフン区切りのUUID文字列を、JavaScriptの文字列に変換する。
+    pub fn to_js_string(&self) -> JsString {
+        JsString::from(self.to_string())
+    }
+
+    /// UUIDの16バイトを、JavaScriptの`Uint8Array`に変換する。
+    pub fn to_uint8_array(&self) -> Uint8Array {
+        Uint8Array::from(self.0.as_bytes().as_slice())
+    }
+
+    /// JavaScriptの値からエンティティIDを生成する。
+    ///
+    /// UUID文字列と16バイトの`Uint8Array`のどちらも受け付ける。
+    /// それ以外の値の場合や、エンティティIDとして解析できない場合は、JavaScriptの`Error`を返す。
+    pub fn from_js_value(value: &JsValue) -> Result<Self, JsError> {
+        if let Some(s) = value.as_string() {
+            return Ok(s.parse()?);
+        }
+        if let Some(array) = value.dyn_ref::<Uint8Array>() {
+            return Ok(Self::try_from(array)?);
+        }
+        Err(JsError::new(&format!(
+            "expected a string or Uint8Array for entity id of `{}`",
+            entity_name::<T>()
+        )))
+    }
+
+    /// TypeScriptのブランド型の宣言を返す。
+    ///
+    /// `EntityId<User>`の場合は、`export type UserId = string & { __brand: "User" };`を返す。
+    /// `d.ts`ファイルを生成する場合に使用する。
+    /// `wasm-bindgen`が生成する型定義に含める場合は、`typescript_entity_ids!`を使用する。
+    ///
+    /// `typescript_entity_ids!`と同様に、エンティティの型名が識別子でない場合（`Vec<User>`などの
+    /// ジェネリック型の場合）は、TypeScriptの型名にできないため、パニックする。
+    pub fn typescript_declaration() -> String {
+        let entity = entity_name::<T>();
+        let mut chars = entity.chars();
+        let is_identifier = chars.next().is_some_and(|c| c == '_' || c.is_alphabetic())
+            && chars.all(|c| c == '_' || c.is_alphanumeric());
+        assert!(
+            is_identifier,
+            "entity name `{entity}` is not a valid TypeScript identifier"
+        );
+        format!("export type {entity}Id = string & {{ __brand: \"{entity}\" }};")
+    }
+}
+
+/// エンティティIDを、UUID文字列のJavaScriptの文字列に変換する。
+impl<T> From<EntityId<T>> for JsValue {
+    fn from(value: EntityId<T>) -> Self {
+        value.to_js_string().into()
+    }
+}
+
+impl<T> From<EntityId<T>> for JsString {
+    fn from(value: EntityId<T>) -> Self {
+        value.to_js_string()
+    }
+}
+
+impl<T> From<EntityId<T>> for Uint8Array {
+    fn from(value: EntityId<T>) -> Self {
+        value.to_uint8_array()
+    }
+}
+
+/// JavaScriptの文字列からエンティティIDを生成する。
+impl<T> TryFrom<&JsString> for EntityId<T> {
+    type Error = ParseEntityIdError;
+
+    fn try_from(value: &JsString) -> Result<Self, Self::Error> {
+        String::from(value).parse()
+    }
+}
+
+/// JavaScriptの`Uint8Array`からエンティティIDを生成する。
+///
+/// 16バイトでない場合はエラーを返す。
+impl<T> TryFrom<&Uint8Array> for EntityId<T> {
+    type Error = ParseEntityIdError;
+
+    fn try_from(value: &Uint8Array) -> Result<Self, Self::Error> {
+        Self::try_from(value.to_vec().as_slice())
+    }
+}
+
+/// JavaScriptの値からエンティティIDを生成する。
+///
+/// `from_js_value`と同じ値を受け付け、`#[wasm_bindgen]`を付けた関数の引数で使用できる。
+impl<T> TryFrom<JsValue> for EntityId<T> {
+    type Error = JsError;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        Self::from_js_value(&value)
+    }
+}
+
+/// エンティティIDの解析エラーを、JavaScriptの`Error`に変換する。
+impl From<ParseEntityIdError> for JsValue {
+    fn from(value: ParseEntityIdError) -> Self {
+        JsError::from(value).into()
+    }
+}
+
+/// エンティティの型ごとのTypeScriptのブランド型を、`wasm-bindgen`が生成する型定義に追加する。
+///
+/// `typescript_entity_ids!(User, Order)`は、次の型定義を追加する。
+/// 使用するクレートは、`wasm-bindgen`に依存している必要がある。
+///
+/// ```typescript
+/// export type UserId = string & { __brand: "User" };
+/// export type OrderId = string & { __brand: "Order" };
+/// ```
+///
+/// 関数の引数や戻り値の型には、`unchecked_param_type`及び`unchecked_return_type`で
+/// ブランド型を指定する。
+///
+/// ```rust
+/// use domain_primitives::entity_id::EntityId;
+/// use wasm_bindgen::prelude::*;
+///
+/// struct User;
+///
+/// domain_primitives::typescript_entity_ids!(User);
+///
+/// #[wasm_bindgen(unchecked_return_type = "UserId")]
+/// pub fn find_user(#[wasm_bindgen(unchecked_param_type = "UserId")] id: JsValue) -> Result<JsValue, JsError> {
+///     let id = EntityId::<User>::try_from(id)?;
+///     Ok(id.into())
+/// }
+/// ```
+#[macro_export]
+macro_rules! typescript_entity_ids {
+    ($($entity:ident),* $(,)?) => {
+        $(
+            const _: () = {
+                #[::wasm_bindgen::prelude::wasm_bindgen(typescript_custom_section)]
+                const TYPESCRIPT_ENTITY_ID: &'static str = concat!(
+                    "export type ",
+                    stringify!($entity),
+                    "Id = string & { __brand: \"",
+                    stringify!($entity),
+                    "\" };"
+                );
+            };
+        )*
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct User;
+
+    struct OrderLine;
+
+    crate::typescript_entity_ids!(User, OrderLine);
+
+    /// エンティティの型ごとのTypeScriptのブランド型を宣言することを確認
+    #[test]
+    fn test_entity_id_typescript_declaration() {
+        assert_eq!(
+            r#"export type UserId = string & { __brand: "User" };"#,
+            EntityId::<User>::typescript_declaration()
+        );
+        assert_eq!(
+            r#"export type OrderLineId = string & { __brand: "OrderLine" };"#,
+            EntityId::<OrderLine>::typescript_declaration()
+        );
+    }
+
+    /// エンティティの型名が識別子でない場合はパニックすることを確認
+    #[test]
+    #[should_panic(expected = "entity name `Vec<User>` is not a valid TypeScript identifier")]
+    fn test_entity_id_typescript_declaration_generic_entity() {
+        EntityId::<Vec<User>>::typescript_declaration();
+    }
+}
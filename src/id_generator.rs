@@ -0,0 +1,366 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "std")]
+use std::cell::RefCell;
+
+use uuid::{Builder, Uuid, Variant, Version};
+
+/// IDジェネレーター
+///
+/// エンティティIDに使用するUUIDを生成する。
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use domain_primitives::entity_id::EntityId;
+/// use domain_primitives::id_generator::{SequentialGenerator, with_generator};
+///
+/// struct Foo;
+/// type FooId = EntityId<Foo>;
+///
+/// let generator = SequentialGenerator::new();
+/// let id = FooId::generate_with(&generator);
+/// assert_eq!("00000000-0000-0000-0000-000000000001", id.to_string());
+///
+/// // スコープ内では、`EntityId::new`が上書きしたジェネレーターを使用する。
+/// let id = with_generator(SequentialGenerator::new(), FooId::new);
+/// assert_eq!("00000000-0000-0000-0000-000000000001", id.to_string());
+///
+/// // `EntityId::new_v7`は`generate_v7`を使用するため、上書きした場合もUUIDv7を生成する。
+/// let id = with_generator(SequentialGenerator::new(), FooId::new_v7);
+/// assert_eq!("00000000-0001-7000-8000-000000000000", id.to_string());
+/// assert!(id.created_at().is_some());
+/// # }
+/// ```
+pub trait IdGenerator {
+    /// UUIDを生成する。
+    fn generate(&self) -> Uuid;
+
+    /// UUIDv7を生成する。
+    ///
+    /// `EntityId::new_v7`は、上書きされたIDジェネレーターのこのメソッドを使用する。
+    /// 既定の実装は、`generate`が生成したUUIDのバージョンを7に、バリアントをRFC 4122に設定する。
+    /// この場合、上位48ビットがタイムスタンプとみなされるため、生成したUUIDが時刻順に並ぶとは限らない。
+    /// 時刻順に並べる必要がある場合は、このメソッドを実装する。
+    fn generate_v7(&self) -> Uuid {
+        Builder::from_bytes(self.generate().into_bytes())
+            .with_version(Version::SortRand)
+            .with_variant(Variant::RFC4122)
+            .into_uuid()
+    }
+}
+
+impl<G: IdGenerator + ?Sized> IdGenerator for &G {
+    fn generate(&self) -> Uuid {
+        (**self).generate()
+    }
+
+    fn generate_v7(&self) -> Uuid {
+        (**self).generate_v7()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<G: IdGenerator + ?Sized> IdGenerator for Box<G> {
+    fn generate(&self) -> Uuid {
+        (**self).generate()
+    }
+
+    fn generate_v7(&self) -> Uuid {
+        (**self).generate_v7()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<G: IdGenerator + ?Sized> IdGenerator for Rc<G> {
+    fn generate(&self) -> Uuid {
+        (**self).generate()
+    }
+
+    fn generate_v7(&self) -> Uuid {
+        (**self).generate_v7()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<G: IdGenerator + ?Sized> IdGenerator for Arc<G> {
+    fn generate(&self) -> Uuid {
+        (**self).generate()
+    }
+
+    fn generate_v7(&self) -> Uuid {
+        (**self).generate_v7()
+    }
+}
+
+/// ランダムなUUIDv4を生成するIDジェネレーター
+#[cfg(feature = "rng")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct V4Generator;
+
+#[cfg(feature = "rng")]
+impl IdGenerator for V4Generator {
+    fn generate(&self) -> Uuid {
+        Uuid::new_v4()
+    }
+
+    #[cfg(feature = "std")]
+    fn generate_v7(&self) -> Uuid {
+        Uuid::now_v7()
+    }
+}
+
+/// 時刻順に並ぶUUIDv7を生成するIDジェネレーター
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct V7Generator;
+
+#[cfg(feature = "std")]
+impl IdGenerator for V7Generator {
+    fn generate(&self) -> Uuid {
+        Uuid::now_v7()
+    }
+
+    fn generate_v7(&self) -> Uuid {
+        Uuid::now_v7()
+    }
+}
+
+/// 連番のUUIDを生成するIDジェネレーター
+///
+/// テストで予測可能なIDを得るために使用する。
+/// 生成するUUIDは、連番を128ビットの整数とみなしたもので、UUIDのバージョンを持たない。
+/// `generate_v7`は、連番をUNIXエポックからのミリ秒とみなしたUUIDv7を生成する。
+#[cfg(target_has_atomic = "64")]
+#[derive(Debug)]
+pub struct SequentialGenerator {
+    next: AtomicU64,
+}
+
+#[cfg(target_has_atomic = "64")]
+impl SequentialGenerator {
+    /// コンストラクタ。
+    ///
+    /// 最初に生成するUUIDは`00000000-0000-0000-0000-000000000001`である。
+    pub fn new() -> Self {
+        Self::starting_at(1)
+    }
+
+    /// 最初に生成するUUIDの連番を指定して、IDジェネレーターを生成する。
+    pub fn starting_at(start: u64) -> Self {
+        Self {
+            next: AtomicU64::new(start),
+        }
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl Default for SequentialGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl IdGenerator for SequentialGenerator {
+    fn generate(&self) -> Uuid {
+        Uuid::from_u128(self.next.fetch_add(1, Ordering::Relaxed) as u128)
+    }
+
+    fn generate_v7(&self) -> Uuid {
+        let millis = self.next.fetch_add(1, Ordering::Relaxed);
+        Builder::from_unix_timestamp_millis(millis, &[0; 10]).into_uuid()
+    }
+}
+
+/// シードから決定的にUUIDv4を生成するIDジェネレーター
+///
+/// 同じシードを与えたIDジェネレーターは、同じ順序で同じUUIDを生成する。
+/// 乱数生成器にはSplitMix64を使用するため、暗号論的に安全ではない。
+/// `generate_v7`は、UNIXエポックから1ミリ秒ずつ進むタイムスタンプを使用したUUIDv7を生成する。
+#[cfg(target_has_atomic = "64")]
+#[derive(Debug)]
+pub struct SeededGenerator {
+    state: AtomicU64,
+    millis: AtomicU64,
+}
+
+#[cfg(target_has_atomic = "64")]
+impl SeededGenerator {
+    const GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+    /// コンストラクタ。
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: AtomicU64::new(seed),
+            millis: AtomicU64::new(0),
+        }
+    }
+
+    fn next_u64(&self) -> u64 {
+        let mut z = self
+            .state
+            .fetch_add(Self::GAMMA, Ordering::Relaxed)
+            .wrapping_add(Self::GAMMA);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl IdGenerator for SeededGenerator {
+    fn generate(&self) -> Uuid {
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&self.next_u64().to_be_bytes());
+        bytes[8..].copy_from_slice(&self.next_u64().to_be_bytes());
+        Builder::from_random_bytes(bytes).into_uuid()
+    }
+
+    fn generate_v7(&self) -> Uuid {
+        let millis = self.millis.fetch_add(1, Ordering::Relaxed);
+        let mut counter_random_bytes = [0u8; 10];
+        counter_random_bytes[..8].copy_from_slice(&self.next_u64().to_be_bytes());
+        counter_random_bytes[8..].copy_from_slice(&self.next_u64().to_be_bytes()[..2]);
+        Builder::from_unix_timestamp_millis(millis, &counter_random_bytes).into_uuid()
+    }
+}
+
+#[cfg(feature = "std")]
+thread_local! {
+    /// 現在のスレッドで上書きされたIDジェネレーター
+    static OVERRIDE: RefCell<Option<Rc<dyn IdGenerator>>> = const { RefCell::new(None) };
+}
+
+/// 現在のスレッドでIDジェネレーターを上書きして、関数を実行する。
+///
+/// 関数の実行中は、`EntityId::new`及び`EntityId::new_v7`が上書きしたIDジェネレーターを使用する。
+/// `EntityId::new_v7`は`IdGenerator::generate_v7`を使用するため、常にUUIDv7を返す。
+/// 関数の実行が終わると（パニックした場合も）、上書きする前のIDジェネレーターに戻す。
+#[cfg(feature = "std")]
+pub fn with_generator<G, F, R>(generator: G, f: F) -> R
+where
+    G: IdGenerator + 'static,
+    F: FnOnce() -> R,
+{
+    struct Restore(Option<Rc<dyn IdGenerator>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            OVERRIDE.with(|o| *o.borrow_mut() = self.0.take());
+        }
+    }
+
+    let previous = OVERRIDE.with(|o| o.borrow_mut().replace(Rc::new(generator)));
+    let _restore = Restore(previous);
+    f()
+}
+
+/// 現在のスレッドで上書きされたIDジェネレーターがあれば、それを使用してUUIDを生成する。
+#[cfg(feature = "std")]
+pub(crate) fn generate_overridden(generate: fn(&dyn IdGenerator) -> Uuid) -> Option<Uuid> {
+    let generator = OVERRIDE.with(|o| o.borrow().clone())?;
+    Some(generate(&*generator))
+}
+
+/// `std`フィーチャーが無効な場合は、IDジェネレーターを上書きできないため、常に`None`を返す。
+#[cfg(all(feature = "rng", not(feature = "std")))]
+pub(crate) fn generate_overridden(_: fn(&dyn IdGenerator) -> Uuid) -> Option<Uuid> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 連番のIDジェネレーターが連番のUUIDを生成することを確認
+    #[test]
+    fn test_sequential_generator() {
+        let generator = SequentialGenerator::starting_at(10);
+        assert_eq!(Uuid::from_u128(10), generator.generate());
+        assert_eq!(Uuid::from_u128(11), generator.generate());
+        let uuid = generator.generate_v7();
+        assert_eq!(uuid::uuid!("00000000-000c-7000-8000-000000000000"), uuid);
+        assert_eq!(Some(uuid::Version::SortRand), uuid.get_version());
+        assert!(uuid < generator.generate_v7());
+    }
+
+    /// 同じシードのIDジェネレーターが同じUUIDv4を生成することを確認
+    #[test]
+    fn test_seeded_generator() {
+        let generator1 = SeededGenerator::new(42);
+        let generator2 = SeededGenerator::new(42);
+        let uuid1 = generator1.generate();
+        assert_eq!(uuid1, generator2.generate());
+        assert_eq!(Some(uuid::Version::Random), uuid1.get_version());
+        assert_ne!(uuid1, generator1.generate());
+        assert_ne!(uuid1, SeededGenerator::new(43).generate());
+    }
+
+    /// 同じシードのIDジェネレーターが、時刻順に並ぶ同じUUIDv7を生成することを確認
+    #[test]
+    fn test_seeded_generator_v7() {
+        let generator1 = SeededGenerator::new(42);
+        let generator2 = SeededGenerator::new(42);
+        let uuid1 = generator1.generate_v7();
+        assert_eq!(uuid1, generator2.generate_v7());
+        assert_eq!(Some(uuid::Version::SortRand), uuid1.get_version());
+        assert!(uuid1 < generator1.generate_v7());
+    }
+
+    /// `generate_v7`の既定の実装が、UUIDv7を生成することを確認
+    #[test]
+    fn test_default_generate_v7() {
+        struct Fixed;
+
+        impl IdGenerator for Fixed {
+            fn generate(&self) -> Uuid {
+                Uuid::from_u128(1)
+            }
+        }
+
+        let uuid = Fixed.generate_v7();
+        assert_eq!(uuid::uuid!("00000000-0000-7000-8000-000000000001"), uuid);
+        assert_eq!(Some(uuid::Version::SortRand), uuid.get_version());
+    }
+
+    /// IDジェネレーターの上書きが関数の実行中だけ有効であることを確認
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_with_generator() {
+        assert_eq!(None, generate_overridden(|g| g.generate()));
+        with_generator(SequentialGenerator::new(), || {
+            assert_eq!(
+                Some(Uuid::from_u128(1)),
+                generate_overridden(|g| g.generate())
+            );
+            with_generator(SequentialGenerator::starting_at(100), || {
+                assert_eq!(
+                    Some(Uuid::from_u128(100)),
+                    generate_overridden(|g| g.generate())
+                );
+            });
+            assert_eq!(
+                Some(Uuid::from_u128(2)),
+                generate_overridden(|g| g.generate())
+            );
+        });
+        assert_eq!(None, generate_overridden(|g| g.generate()));
+    }
+
+    /// 関数がパニックした場合も、IDジェネレーターの上書きが解除されることを確認
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_with_generator_restores_on_panic() {
+        let result = std::panic::catch_unwind(|| {
+            with_generator(SequentialGenerator::new(), || panic!("boom"));
+        });
+        assert!(result.is_err());
+        assert_eq!(None, generate_overridden(|g| g.generate()));
+    }
+}
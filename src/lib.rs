@@ -1 +1,8 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod encoding;
 pub mod entity_id;
+pub mod id_generator;